@@ -0,0 +1,228 @@
+//! Chords built from a root [`Note`] and a set of [`Interval`]s above it.
+//!
+//! Member notes are spelled from the intervals, so a C minor triad yields
+//! E♭ rather than D♯, and an F dominant seventh yields B♭.
+#[cfg(test)]
+mod tests;
+
+use crate::{Accidental, Interval, Note, Pitch, PitchyError};
+
+use Interval::*;
+
+const MAJOR: &[Interval] = &[PerfectUnison, MajorThird, PerfectFifth];
+const MINOR: &[Interval] = &[PerfectUnison, MinorThird, PerfectFifth];
+const DIMINISHED: &[Interval] = &[PerfectUnison, MinorThird, DiminishedFifth];
+const AUGMENTED: &[Interval] = &[PerfectUnison, MajorThird, AugmentedFifth];
+const DOMINANT_SEVENTH: &[Interval] = &[PerfectUnison, MajorThird, PerfectFifth, MinorSeventh];
+const MAJOR_SEVENTH: &[Interval] = &[PerfectUnison, MajorThird, PerfectFifth, MajorSeventh];
+const MINOR_SEVENTH: &[Interval] = &[PerfectUnison, MinorThird, PerfectFifth, MinorSeventh];
+const HALF_DIMINISHED_SEVENTH: &[Interval] =
+    &[PerfectUnison, MinorThird, DiminishedFifth, MinorSeventh];
+const DIMINISHED_SEVENTH: &[Interval] = &[
+    PerfectUnison,
+    MinorThird,
+    DiminishedFifth,
+    DiminishedSeventh,
+];
+
+/// Templates tried by [`Chord::identify`].
+const TEMPLATES: [&[Interval]; 9] = [
+    MAJOR,
+    MINOR,
+    DIMINISHED,
+    AUGMENTED,
+    DOMINANT_SEVENTH,
+    MAJOR_SEVENTH,
+    MINOR_SEVENTH,
+    HALF_DIMINISHED_SEVENTH,
+    DIMINISHED_SEVENTH,
+];
+
+/// A chord: a root note plus the intervals of its members above the root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chord {
+    root: Note,
+    intervals: &'static [Interval],
+}
+
+impl Chord {
+    /// Creates a chord from a root and the intervals of its members above it.
+    ///
+    /// The root itself is only a member if `intervals` contains [`Interval::PerfectUnison`].
+    #[must_use]
+    pub fn new(root: Note, intervals: &'static [Interval]) -> Self {
+        Self { root, intervals }
+    }
+
+    /// Creates a major triad (root, major third, perfect fifth).
+    #[must_use]
+    pub fn major(root: Note) -> Self {
+        Self::new(root, MAJOR)
+    }
+
+    /// Creates a minor triad (root, minor third, perfect fifth).
+    #[must_use]
+    pub fn minor(root: Note) -> Self {
+        Self::new(root, MINOR)
+    }
+
+    /// Creates a diminished triad (root, minor third, diminished fifth).
+    #[must_use]
+    pub fn diminished(root: Note) -> Self {
+        Self::new(root, DIMINISHED)
+    }
+
+    /// Creates an augmented triad (root, major third, augmented fifth).
+    #[must_use]
+    pub fn augmented(root: Note) -> Self {
+        Self::new(root, AUGMENTED)
+    }
+
+    /// Creates a dominant seventh chord (major triad plus a minor seventh).
+    #[must_use]
+    pub fn dominant_seventh(root: Note) -> Self {
+        Self::new(root, DOMINANT_SEVENTH)
+    }
+
+    /// Creates a major seventh chord (major triad plus a major seventh).
+    #[must_use]
+    pub fn major_seventh(root: Note) -> Self {
+        Self::new(root, MAJOR_SEVENTH)
+    }
+
+    /// Creates a minor seventh chord (minor triad plus a minor seventh).
+    #[must_use]
+    pub fn minor_seventh(root: Note) -> Self {
+        Self::new(root, MINOR_SEVENTH)
+    }
+
+    /// Creates a half-diminished seventh chord (diminished triad plus a minor seventh).
+    #[must_use]
+    pub fn half_diminished_seventh(root: Note) -> Self {
+        Self::new(root, HALF_DIMINISHED_SEVENTH)
+    }
+
+    /// Creates a fully diminished seventh chord (diminished triad plus a diminished seventh).
+    #[must_use]
+    pub fn diminished_seventh(root: Note) -> Self {
+        Self::new(root, DIMINISHED_SEVENTH)
+    }
+
+    /// Identifies the chord formed by `pitches`, in any inversion or voicing.
+    ///
+    /// Each pitch is reduced to the pitch class of its nearest MIDI note, and the set of
+    /// classes must match one of the built-in templates exactly (doubled notes are fine).
+    /// Symmetric chords such as augmented triads take the bass as their root. The root is
+    /// placed in the octave at or below the bass and spelled with the fewest accidentals
+    /// across the chord.
+    ///
+    /// Returns `None` if `pitches` is empty, any pitch is outside the MIDI range,
+    /// or no template matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Accidental, Chord, Note, NoteLetter, Pitch};
+    /// use std::str::FromStr;
+    ///
+    /// let pitches = ["E4", "G4", "C5"].map(|name| Pitch::from_str(name).unwrap());
+    /// let chord = Chord::identify(&pitches).unwrap();
+    /// assert_eq!(chord, Chord::major(Note::new(NoteLetter::C, Accidental::Natural, 4)));
+    /// ```
+    #[must_use]
+    pub fn identify(pitches: &[Pitch]) -> Option<Self> {
+        let mut classes = 0u16;
+        let mut bass = None::<u8>;
+        for pitch in pitches {
+            let midi = pitch.try_midi_number().ok()?;
+            classes |= 1 << (midi % 12);
+            bass = Some(bass.map_or(midi, |bass| bass.min(midi)));
+        }
+        let bass = bass?;
+
+        // Candidate roots are tried upwards from the bass, so root position wins ties.
+        (0..12)
+            .map(|offset| (bass + offset) % 12)
+            .filter(|root_class| classes & (1 << root_class) != 0)
+            .find_map(|root_class| {
+                let template = TEMPLATES
+                    .into_iter()
+                    .find(|template| pitch_class_set(template, root_class) == classes)?;
+
+                let below_bass = (bass % 12 + 12 - root_class) % 12;
+                let root_position = bass as i32 - below_bass as i32;
+
+                Note::spellings_at(root_position, Accidental::Sharp)
+                    .map(|root| Self::new(root, template))
+                    .filter_map(|chord| Some((chord.accidental_count()?, chord)))
+                    .min_by_key(|(count, _)| *count)
+                    .map(|(_, chord)| chord)
+            })
+    }
+
+    /// Returns the root note of the chord.
+    #[must_use]
+    pub fn root(&self) -> Note {
+        self.root
+    }
+
+    /// Returns the intervals of the chord members above the root.
+    #[must_use]
+    pub fn intervals(&self) -> &'static [Interval] {
+        self.intervals
+    }
+
+    /// Returns the spelled member notes of the chord, from the root upwards.
+    ///
+    /// A member yields [`PitchyError::Unspelled`] if it would need more than a double
+    /// accidental (e.g., a diminished seventh above C♭).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Accidental, Chord, Note, NoteLetter};
+    ///
+    /// let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    /// let third = Chord::minor(c4).notes().nth(1).unwrap().unwrap();
+    /// assert_eq!(third, Note::new(NoteLetter::E, Accidental::Flat, 4));
+    /// ```
+    pub fn notes(&self) -> impl Iterator<Item = Result<Note, PitchyError>> + '_ {
+        self.intervals
+            .iter()
+            .map(|&interval| self.root.above(interval))
+    }
+
+    /// Returns the pitches of the chord members, from the root upwards.
+    ///
+    /// Fails if any member cannot be spelled or falls outside the MIDI range.
+    /// Only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn pitches(&self) -> Result<Vec<Pitch>, PitchyError> {
+        self.notes().map(|note| Pitch::try_from(note?)).collect()
+    }
+
+    /// Total number of accidentals across the spelled members, or `None` if any is unspellable.
+    fn accidental_count(&self) -> Option<u32> {
+        self.notes()
+            .map(|note| Some((note.ok()?.accidental() as i8).unsigned_abs() as u32))
+            .sum()
+    }
+
+    /// Returns `true` if any chord member has the given pitch class (0–11, C = 0).
+    #[must_use]
+    pub fn contains_pitch_class(&self, pitch_class: u8) -> bool {
+        let root = self.root.chromatic_index();
+
+        self.intervals.iter().any(|interval| {
+            (root + interval.semitones() as i32).rem_euclid(12) == pitch_class as i32
+        })
+    }
+}
+
+/// Bit set of the pitch classes (bit 0 = C) spanned by `intervals` above `root_class`.
+fn pitch_class_set(intervals: &[Interval], root_class: u8) -> u16 {
+    intervals.iter().fold(0, |set, interval| {
+        set | 1 << ((root_class + interval.semitones()) % 12)
+    })
+}
@@ -0,0 +1,146 @@
+use crate::{Accidental, Chord, Note, NoteLetter, PitchyError};
+
+fn note(letter: NoteLetter, accidental: Accidental, octave: i8) -> Note {
+    Note::new(letter, accidental, octave)
+}
+
+#[test]
+fn test_chord_spelling() {
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let datasets = [
+        (
+            Chord::major(note(C, Natural, 4)),
+            [
+                note(C, Natural, 4),
+                note(E, Natural, 4),
+                note(G, Natural, 4),
+            ],
+        ),
+        (
+            Chord::minor(note(C, Natural, 4)),
+            [note(C, Natural, 4), note(E, Flat, 4), note(G, Natural, 4)],
+        ),
+        (
+            Chord::major(note(A, Natural, 3)),
+            [note(A, Natural, 3), note(C, Sharp, 4), note(E, Natural, 4)],
+        ),
+        (
+            Chord::minor(note(B, Flat, 4)),
+            [note(B, Flat, 4), note(D, Flat, 5), note(F, Natural, 5)],
+        ),
+        (
+            Chord::major(note(B, Sharp, 3)),
+            [
+                note(B, Sharp, 3),
+                note(D, DoubleSharp, 4),
+                note(F, DoubleSharp, 4),
+            ],
+        ),
+    ];
+
+    for (chord, expected) in datasets {
+        let mut notes = chord.notes().map(Result::unwrap);
+        assert!(notes.by_ref().eq(expected), "{chord:?}");
+    }
+
+    let seventh = Chord::dominant_seventh(note(F, Natural, 4));
+    let mut notes = seventh.notes().map(Result::unwrap);
+    assert_eq!(notes.nth(3), Some(note(E, Flat, 5)));
+}
+
+#[test]
+fn test_chord_unspellable_member() {
+    use crate::Interval;
+
+    const TOO_FLAT: &[Interval] = &[Interval::PerfectUnison, Interval::DiminishedSeventh];
+    let chord = Chord::new(note(NoteLetter::C, Accidental::Flat, 4), TOO_FLAT);
+
+    let mut notes = chord.notes();
+    assert!(notes.next().unwrap().is_ok());
+    assert_eq!(notes.next(), Some(Err(PitchyError::Unspelled)));
+}
+
+#[test]
+fn test_chord_pitch_classes() {
+    let c_major = Chord::major(note(NoteLetter::C, Accidental::Natural, 4));
+
+    for pc in 0..12 {
+        assert_eq!(
+            c_major.contains_pitch_class(pc),
+            [0, 4, 7].contains(&pc),
+            "pitch class {pc}"
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_chord_pitches() {
+    let c_major = Chord::major(note(NoteLetter::C, Accidental::Natural, 4));
+    let midis: Vec<u8> = c_major
+        .pitches()
+        .unwrap()
+        .iter()
+        .map(|pitch| pitch.try_midi_number().unwrap())
+        .collect();
+    assert_eq!(midis, [60, 64, 67]);
+
+    let too_high = Chord::major(note(NoteLetter::G, Accidental::Natural, 9));
+    assert_eq!(too_high.pitches(), Err(PitchyError::OutOfMidiRange(131)));
+}
+
+#[test]
+fn test_chord_identify() {
+    use Accidental::*;
+    use NoteLetter::*;
+    use core::str::FromStr;
+
+    let identify = |names: &[&str]| {
+        let mut pitches = [crate::Pitch::default(); 8];
+        for (pitch, name) in pitches.iter_mut().zip(names) {
+            *pitch = crate::Pitch::from_str(name).unwrap();
+        }
+        Chord::identify(&pitches[..names.len()])
+    };
+
+    let datasets: [(&[&str], Option<Chord>); 10] = [
+        // First inversion: the root sits below the bass.
+        (&["E4", "G4", "C5"], Some(Chord::major(note(C, Natural, 4)))),
+        (
+            &["C4", "E4", "G4", "C5", "E5"],
+            Some(Chord::major(note(C, Natural, 4))),
+        ),
+        (&["A3", "C4", "E4"], Some(Chord::minor(note(A, Natural, 3)))),
+        (&["Bb3", "D4", "F4"], Some(Chord::major(note(B, Flat, 3)))),
+        (&["C#4", "E4", "G#4"], Some(Chord::minor(note(C, Sharp, 4)))),
+        (
+            &["B3", "D4", "F4"],
+            Some(Chord::diminished(note(B, Natural, 3))),
+        ),
+        (
+            &["E4", "G#4", "C5"],
+            Some(Chord::augmented(note(E, Natural, 4))),
+        ),
+        (
+            &["F3", "G3", "B3", "D4"],
+            Some(Chord::dominant_seventh(note(G, Natural, 2))),
+        ),
+        (&["C4", "D4", "E4"], None),
+        (&[], None),
+    ];
+
+    for (names, expected) in datasets {
+        assert_eq!(identify(names), expected, "{names:?}");
+    }
+
+    assert_eq!(
+        identify(&["D4", "F4", "A4", "C5"]),
+        Some(Chord::minor_seventh(note(D, Natural, 4)))
+    );
+    assert_eq!(
+        identify(&["B3", "D4", "F4", "A4"]),
+        Some(Chord::half_diminished_seventh(note(B, Natural, 3)))
+    );
+}
@@ -1,18 +1,27 @@
+use crate::SymbolError;
+
 /// An error type representing failures when parsing or converting notes or pitches.
 ///
 /// This error may occur when:
 /// - The note name is invalid or unrecognized (e.g., `"H#4"`)
 /// - The octave part cannot be parsed as a number
-/// - The resulting pitch falls outside the valid MIDI range (0–127)
+/// - The resulting pitch falls outside the valid MIDI range (0–127); the variant carries
+///   the computed MIDI number, which is negative when the pitch is too low
 /// - The MIDI number calculation overflows
 /// - A valid note spelling (letter + accidental) cannot be determined
+/// - A scale contains no pitch classes to choose from
+/// - A MIDI message has the wrong status byte, or a channel or data byte out of range
+/// - A frequency is zero, negative or not finite where a real pitch is required
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PitchyError {
     InvalidName,
     InvalidOctave,
-    OutOfMidiRange(u8),
+    OutOfMidiRange(i16),
     MidiOverflow,
     Unspelled,
+    InvalidScale,
+    InvalidMidiMessage,
+    InvalidFrequency,
 }
 
 impl core::fmt::Display for PitchyError {
@@ -38,9 +47,28 @@ impl core::fmt::Display for PitchyError {
                     "The pitch could not be spelled as a standard letter and accidental"
                 )
             }
+            PitchyError::InvalidScale => write!(f, "The scale contains no pitch classes"),
+            PitchyError::InvalidMidiMessage => {
+                write!(f, "The MIDI message is malformed or not a note-on")
+            }
+            PitchyError::InvalidFrequency => {
+                write!(f, "The frequency is not a finite, positive number")
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for PitchyError {}
+
+/// Symbol errors all stem from an unrecognized piece of a note name,
+/// so they map to [`PitchyError::InvalidName`].
+impl From<SymbolError> for PitchyError {
+    fn from(err: SymbolError) -> Self {
+        match err {
+            SymbolError::InvalidAccidental(_)
+            | SymbolError::InvalidAccidentalSymbol
+            | SymbolError::InvalidLetter(_) => PitchyError::InvalidName,
+        }
+    }
+}
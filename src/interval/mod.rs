@@ -0,0 +1,140 @@
+//! Named musical intervals, e.g. a major third or a perfect fifth.
+//!
+//! Each [`Interval`] maps to a fixed number of equal-tempered semitones,
+//! so pitches can be transposed by name instead of by raw semitone counts.
+#[cfg(test)]
+mod tests;
+
+use crate::math::*;
+
+/// A common diatonic interval within an octave.
+///
+/// Enharmonic intervals such as [`Interval::AugmentedFourth`] and
+/// [`Interval::DiminishedFifth`] span the same number of semitones
+/// but are kept distinct to preserve their musical meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    PerfectUnison,
+    MinorSecond,
+    MajorSecond,
+    AugmentedSecond,
+    MinorThird,
+    MajorThird,
+    PerfectFourth,
+    AugmentedFourth,
+    DiminishedFifth,
+    PerfectFifth,
+    AugmentedFifth,
+    MinorSixth,
+    MajorSixth,
+    DiminishedSeventh,
+    MinorSeventh,
+    MajorSeventh,
+    PerfectOctave,
+}
+
+impl Interval {
+    /// Returns the size of the interval in equal-tempered semitones.
+    pub const fn semitones(&self) -> u8 {
+        use Interval::*;
+
+        match self {
+            PerfectUnison => 0,
+            MinorSecond => 1,
+            MajorSecond => 2,
+            AugmentedSecond | MinorThird => 3,
+            MajorThird => 4,
+            PerfectFourth => 5,
+            AugmentedFourth | DiminishedFifth => 6,
+            PerfectFifth => 7,
+            AugmentedFifth | MinorSixth => 8,
+            MajorSixth | DiminishedSeventh => 9,
+            MinorSeventh => 10,
+            MajorSeventh => 11,
+            PerfectOctave => 12,
+        }
+    }
+
+    /// Returns the diatonic number of the interval, counting both endpoints
+    /// (unison = 1, third = 3, octave = 8).
+    ///
+    /// This is how many letter names the interval spans, which determines its spelling:
+    /// a minor third above C is E♭, while an augmented second is D♯.
+    pub const fn number(&self) -> u8 {
+        use Interval::*;
+
+        match self {
+            PerfectUnison => 1,
+            MinorSecond | MajorSecond | AugmentedSecond => 2,
+            MinorThird | MajorThird => 3,
+            PerfectFourth | AugmentedFourth => 4,
+            DiminishedFifth | PerfectFifth | AugmentedFifth => 5,
+            MinorSixth | MajorSixth => 6,
+            DiminishedSeventh | MinorSeventh | MajorSeventh => 7,
+            PerfectOctave => 8,
+        }
+    }
+
+    /// Returns the 5-limit just intonation ratio of the interval as `(numerator, denominator)`,
+    /// e.g. `(5, 4)` for a major third.
+    ///
+    /// The minor seventh uses 9/5; enharmonic intervals get distinct ratios, such as
+    /// 45/32 for the augmented fourth and 64/45 for the diminished fifth.
+    pub const fn just_ratio(&self) -> (u32, u32) {
+        use Interval::*;
+
+        match self {
+            PerfectUnison => (1, 1),
+            MinorSecond => (16, 15),
+            MajorSecond => (9, 8),
+            AugmentedSecond => (75, 64),
+            MinorThird => (6, 5),
+            MajorThird => (5, 4),
+            PerfectFourth => (4, 3),
+            AugmentedFourth => (45, 32),
+            DiminishedFifth => (64, 45),
+            PerfectFifth => (3, 2),
+            AugmentedFifth => (25, 16),
+            MinorSixth => (8, 5),
+            MajorSixth => (5, 3),
+            DiminishedSeventh => (128, 75),
+            MinorSeventh => (9, 5),
+            MajorSeventh => (15, 8),
+            PerfectOctave => (2, 1),
+        }
+    }
+
+    /// Returns the size of the interval in cents in just intonation; see [`Interval::just_ratio`].
+    pub fn just_cents(&self) -> f64 {
+        let (numerator, denominator) = self.just_ratio();
+        1200.0 * log2(numerator as f64 / denominator as f64)
+    }
+
+    /// Returns the size of the interval in cents in equal temperament (100 per semitone).
+    pub fn equal_cents(&self) -> f64 {
+        self.semitones() as f64 * 100.0
+    }
+
+    /// Returns how far the equal-tempered interval deviates from just intonation, in cents.
+    ///
+    /// Positive values mean equal temperament is wider (sharp) than the pure interval:
+    /// a major third is about 13.7 cents sharp, a perfect fifth about 2 cents flat.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Interval;
+    ///
+    /// assert!((Interval::MajorThird.temperament_error() - 13.686).abs() < 1e-3);
+    /// ```
+    pub fn temperament_error(&self) -> f64 {
+        self.equal_cents() - self.just_cents()
+    }
+}
+
+/// The direction in which to apply an [`Interval`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+}
@@ -0,0 +1,57 @@
+use core::str::FromStr;
+
+use crate::{Direction, Interval, Pitch};
+
+#[test]
+fn test_transpose_by_interval() {
+    let datasets = [
+        ("C4", Interval::MajorThird, Direction::Up, 64),
+        ("A4", Interval::PerfectFifth, Direction::Down, 62),
+        ("C4", Interval::PerfectOctave, Direction::Up, 72),
+        ("E4", Interval::MinorSecond, Direction::Down, 63),
+        ("F4", Interval::AugmentedFourth, Direction::Up, 71),
+        ("G4", Interval::PerfectUnison, Direction::Down, 67),
+    ];
+
+    for (name, interval, direction, expected) in datasets {
+        let pitch = Pitch::from_str(name).unwrap();
+        let transposed = pitch.transpose_by(interval, direction);
+        assert_eq!(
+            transposed.try_midi_number().unwrap(),
+            expected,
+            "{name} {interval:?} {direction:?}"
+        );
+    }
+}
+
+#[test]
+fn test_enharmonic_intervals() {
+    assert_eq!(
+        Interval::AugmentedFourth.semitones(),
+        Interval::DiminishedFifth.semitones()
+    );
+    assert_ne!(Interval::AugmentedFourth, Interval::DiminishedFifth);
+    assert_eq!(Interval::AugmentedSecond.semitones(), 3);
+}
+
+#[test]
+fn test_temperament_error() {
+    let datasets = [
+        (Interval::MajorThird, 386.314, 13.686),
+        (Interval::PerfectFifth, 701.955, -1.955),
+        (Interval::MinorThird, 315.641, -15.641),
+        (Interval::PerfectFourth, 498.045, 1.955),
+    ];
+
+    for (interval, just, error) in datasets {
+        assert!((interval.just_cents() - just).abs() < 1e-3, "{interval:?}");
+        assert!(
+            (interval.temperament_error() - error).abs() < 1e-3,
+            "{interval:?}"
+        );
+    }
+
+    assert_eq!(Interval::MajorThird.equal_cents(), 400.0);
+    assert_eq!(Interval::PerfectUnison.temperament_error(), 0.0);
+    assert_eq!(Interval::PerfectOctave.temperament_error(), 0.0);
+}
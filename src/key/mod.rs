@@ -0,0 +1,101 @@
+//! Minimal major and minor key model for diatonic spelling.
+//!
+//! A [`Key`] knows which accidental each letter takes, e.g. F♯ in G major,
+//! which is all [`Note::transpose_diatonic`](crate::Note::transpose_diatonic) needs.
+#[cfg(test)]
+mod tests;
+
+use crate::{Accidental, NoteLetter, SymbolError};
+
+/// Semitones above the tonic of each scale degree, for [`Mode::Major`].
+const MAJOR_STEPS: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+/// Semitones above the tonic of each scale degree, for [`Mode::Minor`] (natural minor).
+const MINOR_STEPS: [i8; 7] = [0, 2, 3, 5, 7, 8, 10];
+
+/// The mode of a [`Key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// The major scale (Ionian).
+    Major,
+    /// The natural minor scale (Aeolian).
+    Minor,
+}
+
+/// A major or minor key, identified by its spelled tonic and mode.
+///
+/// # Examples
+///
+/// ```
+/// use pitchy::{Accidental, Key, NoteLetter};
+///
+/// let g_major = Key::major(NoteLetter::G, Accidental::Natural);
+/// assert_eq!(g_major.accidental_for(NoteLetter::F), Ok(Accidental::Sharp));
+/// assert_eq!(g_major.accidental_for(NoteLetter::C), Ok(Accidental::Natural));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    tonic: NoteLetter,
+    accidental: Accidental,
+    mode: Mode,
+}
+
+impl Key {
+    /// Creates a key from its tonic letter, tonic accidental and mode.
+    #[must_use]
+    pub fn new(tonic: NoteLetter, accidental: Accidental, mode: Mode) -> Self {
+        Self {
+            tonic,
+            accidental,
+            mode,
+        }
+    }
+
+    /// Creates a major key, e.g. `Key::major(NoteLetter::E, Accidental::Flat)` for E♭ major.
+    #[must_use]
+    pub fn major(tonic: NoteLetter, accidental: Accidental) -> Self {
+        Self::new(tonic, accidental, Mode::Major)
+    }
+
+    /// Creates a natural minor key.
+    #[must_use]
+    pub fn minor(tonic: NoteLetter, accidental: Accidental) -> Self {
+        Self::new(tonic, accidental, Mode::Minor)
+    }
+
+    /// Returns the letter of the tonic.
+    #[must_use]
+    pub fn tonic(&self) -> NoteLetter {
+        self.tonic
+    }
+
+    /// Returns the accidental of the tonic.
+    #[must_use]
+    pub fn tonic_accidental(&self) -> Accidental {
+        self.accidental
+    }
+
+    /// Returns the mode of the key.
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the accidental that `letter` takes in this key.
+    ///
+    /// Fails with [`SymbolError::InvalidAccidental`] for theoretical keys that would need
+    /// more than a double accidental (e.g., the F in D♯♯ major).
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn accidental_for(&self, letter: NoteLetter) -> Result<Accidental, SymbolError> {
+        let steps = match self.mode {
+            Mode::Major => &MAJOR_STEPS,
+            Mode::Minor => &MINOR_STEPS,
+        };
+        let degree = (letter.index() - self.tonic.index()).rem_euclid(7);
+
+        let target = self.tonic as i8 + self.accidental as i8 + steps[degree as usize];
+        // Offset from the natural letter, normalised to the nearest octave
+        let offset = (target - letter as i8 + 6).rem_euclid(12) - 6;
+
+        Accidental::try_from(offset)
+    }
+}
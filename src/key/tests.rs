@@ -0,0 +1,45 @@
+use crate::{Accidental, Key, Mode, NoteLetter, SymbolError};
+
+#[test]
+fn test_key_signatures() {
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let letters = [C, D, E, F, G, A, B];
+    let datasets = [
+        (Key::major(C, Natural), [Natural; 7]),
+        (
+            Key::major(G, Natural),
+            [Natural, Natural, Natural, Sharp, Natural, Natural, Natural],
+        ),
+        (
+            Key::major(E, Flat),
+            [Natural, Natural, Flat, Natural, Natural, Flat, Flat],
+        ),
+        (Key::minor(A, Natural), [Natural; 7]),
+        (
+            Key::minor(C, Sharp),
+            [Sharp, Sharp, Natural, Sharp, Sharp, Natural, Natural],
+        ),
+        (Key::major(C, Sharp), [Sharp; 7]),
+        (Key::major(C, Flat), [Flat; 7]),
+    ];
+
+    for (key, expected) in datasets {
+        for (letter, accidental) in letters.into_iter().zip(expected) {
+            assert_eq!(
+                key.accidental_for(letter),
+                Ok(accidental),
+                "{letter:?} in {key:?}"
+            );
+        }
+    }
+
+    let g_sharp_major = Key::major(G, Sharp);
+    assert_eq!(g_sharp_major.accidental_for(F), Ok(DoubleSharp));
+    let d_double_sharp_major = Key::new(D, DoubleSharp, Mode::Major);
+    assert_eq!(
+        d_double_sharp_major.accidental_for(F),
+        Err(SymbolError::InvalidAccidental(3))
+    );
+}
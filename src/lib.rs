@@ -8,8 +8,11 @@
 //!
 //! - Convert frequencies to MIDI note numbers and back
 //! - Transpose pitches by semitones with precise frequency calculations
+//! - Transpose by named intervals such as [`Interval::MajorThird`]
+//! - Build chords with correctly spelled members via [`Chord`]
 //! - Query pitch octave and MIDI number mappings
 //! - Parse standard note strings like `"C#4"` into [`Pitch`] values
+//! - Plug in alternative tunings through the [`Temperament`] trait
 //! - Optional formatting of symbolic note names like `"A4"` when `std` is enabled
 //! - Uses the [`libm`](https://crates.io/crates/libm) math backend in `no_std` mode (via the `libm` feature)
 //!
@@ -28,11 +31,34 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod chord;
 mod error;
+mod interval;
+mod key;
 mod math;
 mod note;
 mod pitch;
+mod pitch_class;
+mod temperament;
 
+pub use chord::Chord;
 pub use error::PitchyError;
-pub use note::{Accidental, Note, NoteLetter};
-pub use pitch::Pitch;
+pub use interval::{Direction, Interval};
+pub use key::{Key, Mode};
+pub use note::{
+    Accidental, MIDI_NOTE_NAMES, Note, NoteLetter, OctaveConvention, SpellingPreference,
+    SymbolError, circle_of_fifths, fifths_from, midi_note_name, nearest_note_str, parse,
+};
+#[cfg(feature = "alloc")]
+pub use pitch::PitchCache;
+pub use pitch::{
+    MIDI_FREQUENCIES, NoteDetection, OrderedPitch, Pitch, PitchIterExt, PitchQuantizer,
+    RoundingMode, Transposed, transpose_all,
+};
+#[cfg(feature = "std")]
+pub use pitch::{pitch_table, print_pitch_table};
+pub use pitch_class::PitchClass;
+pub use temperament::{EqualTemperament, Temperament, TuningContext};
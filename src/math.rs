@@ -40,3 +40,27 @@ pub fn round(x: f64) -> f64 {
 pub fn round(x: f64) -> f64 {
     libm::round(x)
 }
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
@@ -1,13 +1,231 @@
 //! Symbolic note representation — e.g., "C#4" or "Bb2".
 //! Includes spelling logic via [`NoteLetter`] and [`Accidental`].
 
+mod parse;
 mod symbol;
 #[cfg(test)]
 mod tests;
 
-pub use symbol::{Accidental, NoteLetter};
+pub(crate) use parse::{parse_helmholtz, parse_note, parse_pitch_class};
 
-use crate::{Pitch, PitchyError};
+pub use symbol::{Accidental, NoteLetter, SymbolError};
+
+use core::cmp::Ordering;
+
+use crate::{Interval, Key, Pitch, PitchyError};
+
+/// Accidentals in the order they are preferred when spelling a pitch:
+/// natural and sharp spellings first, double accidentals last.
+const SPELLING_ORDER: [Accidental; 5] = [
+    Accidental::Natural,
+    Accidental::Sharp,
+    Accidental::Flat,
+    Accidental::DoubleSharp,
+    Accidental::DoubleFlat,
+];
+
+/// Sharp-spelled names of every MIDI note number, from `"C-1"` (0) to `"G9"` (127).
+///
+/// Matches the spelling of `Note::try_from(Pitch)` and needs no allocation.
+pub const MIDI_NOTE_NAMES: [&str; 128] = [
+    "C-1", "C#-1", "D-1", "D#-1", "E-1", "F-1", "F#-1", "G-1", "G#-1", "A-1", "A#-1", "B-1", "C0",
+    "C#0", "D0", "D#0", "E0", "F0", "F#0", "G0", "G#0", "A0", "A#0", "B0", "C1", "C#1", "D1",
+    "D#1", "E1", "F1", "F#1", "G1", "G#1", "A1", "A#1", "B1", "C2", "C#2", "D2", "D#2", "E2", "F2",
+    "F#2", "G2", "G#2", "A2", "A#2", "B2", "C3", "C#3", "D3", "D#3", "E3", "F3", "F#3", "G3",
+    "G#3", "A3", "A#3", "B3", "C4", "C#4", "D4", "D#4", "E4", "F4", "F#4", "G4", "G#4", "A4",
+    "A#4", "B4", "C5", "C#5", "D5", "D#5", "E5", "F5", "F#5", "G5", "G#5", "A5", "A#5", "B5", "C6",
+    "C#6", "D6", "D#6", "E6", "F6", "F#6", "G6", "G#6", "A6", "A#6", "B6", "C7", "C#7", "D7",
+    "D#7", "E7", "F7", "F#7", "G7", "G#7", "A7", "A#7", "B7", "C8", "C#8", "D8", "D#8", "E8", "F8",
+    "F#8", "G8", "G#8", "A8", "A#8", "B8", "C9", "C#9", "D9", "D#9", "E9", "F9", "F#9", "G9",
+];
+
+/// Returns the sharp-spelled name of a MIDI note number (e.g., `"A4"` for 69),
+/// or `None` if `midi` is above 127.
+///
+/// # Examples
+/// ```
+/// use pitchy::midi_note_name;
+///
+/// assert_eq!(midi_note_name(61), Some("C#4"));
+/// assert_eq!(midi_note_name(128), None);
+/// ```
+#[must_use]
+pub fn midi_note_name(midi: u8) -> Option<&'static str> {
+    MIDI_NOTE_NAMES.get(midi as usize).copied()
+}
+
+/// Returns the sharp-spelled name stem (e.g., `"A"`, `"C#"`) and octave of the MIDI note
+/// nearest to `pitch`, or `None` outside the MIDI range.
+///
+/// The stem is a slice of [`MIDI_NOTE_NAMES`], so no allocation is needed and this works
+/// in `no_std` environments; the caller assembles the label.
+///
+/// # Examples
+/// ```
+/// use pitchy::{Pitch, nearest_note_str};
+///
+/// assert_eq!(nearest_note_str(&Pitch::new(445.0)), Some(("A", 4)));
+/// assert_eq!(nearest_note_str(&Pitch::new(27.5 * 1.06)), Some(("A#", 0)));
+/// ```
+#[must_use]
+pub fn nearest_note_str(pitch: &Pitch) -> Option<(&'static str, i8)> {
+    let midi = pitch.try_midi_number().ok()?;
+    let name = MIDI_NOTE_NAMES[midi as usize];
+    let stem = name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '-');
+
+    Some((stem, crate::pitch::midi_octave(midi)))
+}
+
+/// Parses a note name such as `"Db4"` into both its spelled [`Note`] and its [`Pitch`].
+///
+/// Accepts the same names as [`Pitch`]'s `FromStr`. The note keeps the spelling as typed,
+/// which converting the pitch back with `Note::try_from` would lose (it always uses sharps).
+///
+/// # Errors
+/// Returns the same errors as parsing a [`Pitch`], including
+/// [`PitchyError::OutOfMidiRange`] for notes outside the MIDI range.
+///
+/// # Examples
+/// ```
+/// use pitchy::Accidental;
+///
+/// let (note, pitch) = pitchy::parse("Db4").unwrap();
+/// assert_eq!(note.accidental(), Accidental::Flat);
+/// assert!((pitch.frequency() - 277.18).abs() < 0.01);
+/// ```
+#[must_use = "this returns a Result that may hold an error"]
+pub fn parse(s: &str) -> Result<(Note, Pitch), PitchyError> {
+    let note = parse_note(s)?;
+
+    Ok((note, Pitch::try_from(note)?))
+}
+
+/// Returns the twelve major keys in circle-of-fifths order, as tonics in octave 4.
+///
+/// Starts at C and moves clockwise through the sharp keys to F♯, then continues with
+/// the flat keys D♭ to F, using each key's conventional spelling.
+///
+/// # Examples
+/// ```
+/// use pitchy::{NoteLetter, circle_of_fifths};
+///
+/// let letters = circle_of_fifths().map(|note| note.letter());
+/// assert_eq!(letters[..3], [NoteLetter::C, NoteLetter::G, NoteLetter::D]);
+/// ```
+#[must_use]
+pub fn circle_of_fifths() -> [Note; 12] {
+    use Accidental::{Flat, Natural, Sharp};
+    use NoteLetter::*;
+
+    [
+        (C, Natural),
+        (G, Natural),
+        (D, Natural),
+        (A, Natural),
+        (E, Natural),
+        (B, Natural),
+        (F, Sharp),
+        (D, Flat),
+        (A, Flat),
+        (E, Flat),
+        (B, Flat),
+        (F, Natural),
+    ]
+    .map(|(letter, accidental)| Note::new(letter, accidental, 4))
+}
+
+/// Yields `count` notes, starting at `root` and rising by perfect fifths, spelled exactly.
+///
+/// Every note keeps the root's octave number, so the sequence walks around the circle
+/// rather than climbing the keyboard. Sharps accumulate (C, G, …, F♯, C♯, …), and once a
+/// note would need more than a double sharp the iterator yields
+/// [`PitchyError::Unspelled`] and stops.
+///
+/// # Examples
+/// ```
+/// use pitchy::{Accidental, Note, NoteLetter, fifths_from};
+///
+/// let b_flat3 = Note::new(NoteLetter::B, Accidental::Flat, 3);
+/// let f3 = fifths_from(b_flat3, 2).last().unwrap().unwrap();
+/// assert_eq!(f3, Note::new(NoteLetter::F, Accidental::Natural, 3));
+/// ```
+pub fn fifths_from(root: Note, count: usize) -> impl Iterator<Item = Result<Note, PitchyError>> {
+    core::iter::successors(
+        Some(Ok(root)),
+        move |previous: &Result<Note, PitchyError>| {
+            let next = previous.as_ref().ok()?.above(Interval::PerfectFifth);
+            Some(next.map(|note| Note::new(note.letter, note.accidental, root.octave)))
+        },
+    )
+    .take(count)
+}
+
+/// Which accidental to use when spelling a black-key pitch, e.g. C♯ or D♭.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpellingPreference {
+    /// Spell black keys with sharps (C♯, D♯, F♯, G♯, A♯).
+    #[default]
+    Sharp,
+    /// Spell black keys with flats (D♭, E♭, G♭, A♭, B♭).
+    Flat,
+}
+
+/// How octave numbers are written in note names, relative to the MIDI standard where
+/// middle C (MIDI 60) is C4.
+///
+/// The written octave is the standard octave plus `offset`, so Yamaha's convention, with
+/// middle C as C3, has an offset of -1. The default matches the MIDI standard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OctaveConvention {
+    /// Octaves added to the standard octave number when writing a name.
+    pub offset: i8,
+}
+
+impl OctaveConvention {
+    /// Scientific pitch notation, the MIDI standard: middle C is C4.
+    pub const SCIENTIFIC: Self = Self { offset: 0 };
+
+    /// The Yamaha convention: middle C is C3.
+    pub const YAMAHA: Self = Self { offset: -1 };
+}
+
+const SHARP_SPELLINGS: [(NoteLetter, Accidental); 12] = {
+    use Accidental::{Natural, Sharp};
+    use NoteLetter::*;
+    [
+        (C, Natural),
+        (C, Sharp),
+        (D, Natural),
+        (D, Sharp),
+        (E, Natural),
+        (F, Natural),
+        (F, Sharp),
+        (G, Natural),
+        (G, Sharp),
+        (A, Natural),
+        (A, Sharp),
+        (B, Natural),
+    ]
+};
+
+const FLAT_SPELLINGS: [(NoteLetter, Accidental); 12] = {
+    use Accidental::{Flat, Natural};
+    use NoteLetter::*;
+    [
+        (C, Natural),
+        (D, Flat),
+        (D, Natural),
+        (E, Flat),
+        (E, Natural),
+        (F, Natural),
+        (G, Flat),
+        (G, Natural),
+        (A, Flat),
+        (A, Natural),
+        (B, Flat),
+        (B, Natural),
+    ]
+};
 
 /// A musical note spelled with a letter, accidental, and octave.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +239,7 @@ impl Note {
     /// Creates a new symbolic note.
     ///
     /// This does not check whether the resulting note is within the valid MIDI range.
+    #[must_use]
     pub fn new(letter: NoteLetter, accidental: Accidental, octave: i8) -> Self {
         Self {
             letter,
@@ -29,27 +248,629 @@ impl Note {
         }
     }
 
+    /// Creates a new symbolic note, checking that it lies within the MIDI range (C-1 to G9).
+    ///
+    /// Returns `PitchyError::OutOfMidiRange(midi)` with the note's MIDI number otherwise.
+    /// Use [`Note::new`] when the range check is not needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, PitchyError};
+    ///
+    /// assert!(Note::try_new(NoteLetter::C, Accidental::Natural, 4).is_ok());
+    /// assert_eq!(
+    ///     Note::try_new(NoteLetter::C, Accidental::Natural, 10),
+    ///     Err(PitchyError::OutOfMidiRange(132))
+    /// );
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn try_new(
+        letter: NoteLetter,
+        accidental: Accidental,
+        octave: i8,
+    ) -> Result<Self, PitchyError> {
+        let note = Self::new(letter, accidental, octave);
+        let midi = note.chromatic_index();
+
+        if (0..=127).contains(&midi) {
+            Ok(note)
+        } else {
+            Err(PitchyError::OutOfMidiRange(midi as i16))
+        }
+    }
+
+    /// Spells a MIDI note number (0–127) directly, without going through a frequency.
+    ///
+    /// White keys are always natural; black keys use `preference`.
+    /// Returns `PitchyError::OutOfMidiRange(midi)` if `midi` is above 127.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, SpellingPreference};
+    ///
+    /// let d_flat4 = Note::try_from_midi_number(61, SpellingPreference::Flat).unwrap();
+    /// assert_eq!(d_flat4, Note::new(NoteLetter::D, Accidental::Flat, 4));
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn try_from_midi_number(
+        midi: u8,
+        preference: SpellingPreference,
+    ) -> Result<Self, PitchyError> {
+        if midi > 127 {
+            return Err(PitchyError::OutOfMidiRange(midi as i16));
+        }
+
+        let spellings = match preference {
+            SpellingPreference::Sharp => &SHARP_SPELLINGS,
+            SpellingPreference::Flat => &FLAT_SPELLINGS,
+        };
+        let (letter, accidental) = spellings[(midi % 12) as usize];
+
+        Ok(Self::new(
+            letter,
+            accidental,
+            crate::pitch::midi_octave(midi),
+        ))
+    }
+
     /// Returns the octave number of the note, based on the MIDI mapping.
     /// For example, MIDI 69 (A4) returns 4, and MIDI 0 (C-1) returns -1.
+    #[must_use]
     pub fn octave(&self) -> i8 {
         self.octave
     }
 
+    /// Returns the same spelling in another octave, e.g. `Db4` to `Db2`.
+    ///
+    /// Only the octave number changes, so the letter and accidental are kept exactly.
+    #[must_use]
+    pub fn with_octave(&self, octave: i8) -> Note {
+        Note::new(self.letter, self.accidental, octave)
+    }
+
+    /// Moves the note by `delta` octaves, keeping its spelling exactly.
+    ///
+    /// The octave saturates at the `i8` limits instead of wrapping.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    /// assert_eq!(d_flat4.shifted_octave(1), Note::new(NoteLetter::D, Accidental::Flat, 5));
+    /// ```
+    #[must_use]
+    pub fn shifted_octave(&self, delta: i8) -> Note {
+        self.with_octave(self.octave.saturating_add(delta))
+    }
+
+    /// Returns the octave the note actually sounds in, after accidentals cross the B–C boundary.
+    ///
+    /// This differs from [`Note::octave`] only for spellings like `Cb4`, which sounds as B3,
+    /// and `B#4`, which sounds as C5. The result saturates at the `i8` limits.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// assert_eq!(Note::new(NoteLetter::C, Accidental::Flat, 4).effective_octave(), 3);
+    /// assert_eq!(Note::new(NoteLetter::B, Accidental::Sharp, 4).effective_octave(), 5);
+    /// ```
+    #[must_use]
+    pub fn effective_octave(&self) -> i8 {
+        let octave = self.chromatic_index().div_euclid(12) - 1;
+
+        octave.clamp(i8::MIN as i32, i8::MAX as i32) as i8
+    }
+
     /// Returns the [`NoteLetter`] of the note (e.g., C, D, E, etc.).
+    #[must_use]
     pub fn letter(&self) -> NoteLetter {
         self.letter
     }
 
     /// Returns the [`Accidental`] of the note (e.g., ♯, ♭, 𝄪, etc.).
+    #[must_use]
     pub fn accidental(&self) -> Accidental {
         self.accidental
     }
 
+    /// Returns `true` if the note is spelled without an accidental.
+    ///
+    /// This is about spelling, not sound: `Cb4` and `E#4` are not naturals even though
+    /// they sound on white keys (B3 and F4). Use [`Pitch::is_natural`] for the key color.
+    #[must_use]
+    pub fn is_natural(&self) -> bool {
+        self.accidental == Accidental::Natural
+    }
+
+    /// Raises the accidental by one step, keeping the letter and octave (C → C♯ → C𝄪).
+    ///
+    /// Returns [`SymbolError::InvalidAccidental`] when the note is already double sharp.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let b_flat3 = Note::new(NoteLetter::B, Accidental::Flat, 3);
+    /// assert_eq!(b_flat3.sharpen().unwrap().accidental(), Accidental::Natural);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn sharpen(&self) -> Result<Note, SymbolError> {
+        self.with_accidental_step(1)
+    }
+
+    /// Lowers the accidental by one step, keeping the letter and octave (C → C♭ → C𝄫).
+    ///
+    /// Returns [`SymbolError::InvalidAccidental`] when the note is already double flat.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn flatten(&self) -> Result<Note, SymbolError> {
+        self.with_accidental_step(-1)
+    }
+
+    fn with_accidental_step(&self, step: i8) -> Result<Note, SymbolError> {
+        let accidental = Accidental::try_from(self.accidental as i8 + step)?;
+
+        Ok(Note::new(self.letter, accidental, self.octave))
+    }
+
+    /// Returns the directed distance in semitones from this note to `other`.
+    ///
+    /// Positive values mean `other` is higher, negative values mean it is lower.
+    /// This is pure arithmetic on the spelling, so it also works for notes outside the MIDI range.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    /// let g4 = Note::new(NoteLetter::G, Accidental::Natural, 4);
+    /// assert_eq!(c4.semitones_to(&g4), 7);
+    /// assert_eq!(g4.semitones_to(&c4), -7);
+    /// ```
+    #[must_use]
+    pub fn semitones_to(&self, other: &Note) -> i32 {
+        other.chromatic_index() - self.chromatic_index()
+    }
+
+    /// Returns `true` if both notes sound the same pitch, however they are spelled.
+    ///
+    /// `==` compares spellings, so C♯4 and D♭4 are enharmonic but not equal.
+    /// To sort by sounding pitch use [`Note::cmp_by_pitch`].
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    /// let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    /// assert!(c_sharp4.is_enharmonic(&d_flat4));
+    /// assert_ne!(c_sharp4, d_flat4);
+    /// ```
+    #[must_use]
+    pub fn is_enharmonic(&self, other: &Note) -> bool {
+        self.chromatic_index() == other.chromatic_index()
+    }
+
+    /// Compares notes by sounding pitch, as if on a piano keyboard.
+    ///
+    /// Enharmonic spellings are ordered by accidental, flattest first,
+    /// so D♭4 sorts before C♯4 and C4 before B♯3.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    /// use std::cmp::Ordering;
+    ///
+    /// let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    /// let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    /// assert_eq!(c_sharp4.cmp_by_pitch(&d_flat4), Ordering::Greater);
+    /// ```
+    #[must_use]
+    pub fn cmp_by_pitch(&self, other: &Note) -> Ordering {
+        self.chromatic_index()
+            .cmp(&other.chromatic_index())
+            .then((self.accidental as i8).cmp(&(other.accidental as i8)))
+    }
+
+    /// Compares notes by written position on the staff: octave, then letter, then accidental.
+    ///
+    /// This is the order used by [`Ord`] for `Note`, so C♯4 sorts before D♭4
+    /// and B♯3 before C4 even though they sound the same.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    /// use std::cmp::Ordering;
+    ///
+    /// let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    /// let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    /// assert_eq!(c_sharp4.cmp_by_staff(&d_flat4), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn cmp_by_staff(&self, other: &Note) -> Ordering {
+        self.octave
+            .cmp(&other.octave)
+            .then(self.letter.index().cmp(&other.letter.index()))
+            .then((self.accidental as i8).cmp(&(other.accidental as i8)))
+    }
+
+    /// Returns every spelling of this note's pitch whose accidental is no larger
+    /// in magnitude than `max_accidental`, including this note itself.
+    ///
+    /// Spellings are yielded in the usual preference order: naturals, sharps, flats,
+    /// then double accidentals. The octave follows the letter, so `B#3` and `C4` are equivalent.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    /// let spellings: Vec<Note> = c_sharp4
+    ///     .enharmonic_equivalents_within(Accidental::Sharp)
+    ///     .collect();
+    /// assert_eq!(
+    ///     spellings,
+    ///     [c_sharp4, Note::new(NoteLetter::D, Accidental::Flat, 4)]
+    /// );
+    /// ```
+    pub fn enharmonic_equivalents_within(
+        &self,
+        max_accidental: Accidental,
+    ) -> impl Iterator<Item = Note> {
+        Self::spellings_at(self.chromatic_index(), max_accidental)
+    }
+
+    /// Spellings of the absolute chromatic `position`, in [`SPELLING_ORDER`].
+    pub(crate) fn spellings_at(
+        position: i32,
+        max_accidental: Accidental,
+    ) -> impl Iterator<Item = Note> {
+        let limit = (max_accidental as i8).abs();
+
+        SPELLING_ORDER
+            .into_iter()
+            .filter(move |accidental| (*accidental as i8).abs() <= limit)
+            .flat_map(move |accidental| {
+                NoteLetter::all().into_iter().filter_map(move |letter| {
+                    let base = position - letter as i32 - accidental as i32;
+                    if base.rem_euclid(12) != 0 {
+                        return None;
+                    }
+                    let octave = i8::try_from(base / 12 - 1).ok()?;
+                    Some(Note::new(letter, accidental, octave))
+                })
+            })
+    }
+
+    /// Returns the movable-do solfège syllable of this note relative to `tonic`
+    /// (e.g., `"Do"`, `"Re"`, `"Mi"`), assuming a major key.
+    ///
+    /// Raised degrees use the chromatic syllables `"Di"`, `"Ri"`, `"Fi"`, `"Si"`, `"Li"`
+    /// and lowered ones `"Ra"`, `"Me"`, `"Se"`, `"Le"`, `"Te"`. Returns `None` for
+    /// alterations without a standard syllable (e.g., double accidentals or `E#` in C).
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let e4 = Note::new(NoteLetter::E, Accidental::Natural, 4);
+    /// assert_eq!(e4.solfege(NoteLetter::C), Some("Mi"));
+    /// assert_eq!(e4.solfege(NoteLetter::D), Some("Re"));
+    /// ```
+    #[must_use]
+    pub fn solfege(&self, tonic: NoteLetter) -> Option<&'static str> {
+        const MAJOR: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+        // (lowered, diatonic, raised) syllables for each scale degree
+        const SYLLABLES: [[Option<&str>; 3]; 7] = [
+            [None, Some("Do"), Some("Di")],
+            [Some("Ra"), Some("Re"), Some("Ri")],
+            [Some("Me"), Some("Mi"), None],
+            [None, Some("Fa"), Some("Fi")],
+            [Some("Se"), Some("Sol"), Some("Si")],
+            [Some("Le"), Some("La"), Some("Li")],
+            [Some("Te"), Some("Ti"), None],
+        ];
+
+        let degree = (self.letter.index() - tonic.index()).rem_euclid(7) as usize;
+        let semitones = (self.letter as i8 + self.accidental as i8 - tonic as i8).rem_euclid(12);
+        let alteration = (semitones - MAJOR[degree] + 6).rem_euclid(12) - 6;
+
+        match alteration {
+            -1..=1 => SYLLABLES[degree][(alteration + 1) as usize],
+            _ => None,
+        }
+    }
+
+    /// Returns the fixed-do solfège syllable of this note, where C is always `"Do"`.
+    ///
+    /// Equivalent to [`Note::solfege`] with a tonic of C.
+    #[must_use]
+    pub fn solfege_fixed(&self) -> Option<&'static str> {
+        self.solfege(NoteLetter::C)
+    }
+
+    /// Spells the note an `interval` above this one, using the interval's letter distance.
+    pub(crate) fn above(&self, interval: Interval) -> Result<Note, PitchyError> {
+        let steps = self.letter.index() as i16 + interval.number() as i16 - 1;
+        let letter = self.letter.step(interval.number() as i32 - 1);
+        let octave =
+            i8::try_from(self.octave as i16 + steps / 7).map_err(|_| PitchyError::MidiOverflow)?;
+
+        let target = self.chromatic_index() + interval.semitones() as i32;
+        let natural = Note::new(letter, Accidental::Natural, octave).chromatic_index();
+        let accidental = i8::try_from(target - natural)
+            .ok()
+            .and_then(|offset| Accidental::try_from(offset).ok())
+            .ok_or(PitchyError::Unspelled)?;
+
+        Ok(Note::new(letter, accidental, octave))
+    }
+
+    /// Moves this note by `degrees` scale steps within `key`, respelling it to fit the key.
+    ///
+    /// Degrees count letter steps, so a third up is `2` and a fifth down is `-4`. The
+    /// result takes the key's accidental for its letter, regardless of this note's own
+    /// accidental: in C major C4 up a third is E4, and in G major E4 up a third is G4
+    /// while D4 up a third is F♯4.
+    ///
+    /// Returns `PitchyError::MidiOverflow` if the octave overflows, or
+    /// `PitchyError::InvalidName` if the key needs more than a double accidental.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Key, Note, NoteLetter};
+    ///
+    /// let g_major = Key::major(NoteLetter::G, Accidental::Natural);
+    /// let d4 = Note::new(NoteLetter::D, Accidental::Natural, 4);
+    /// assert_eq!(
+    ///     d4.transpose_diatonic(2, g_major).unwrap(),
+    ///     Note::new(NoteLetter::F, Accidental::Sharp, 4)
+    /// );
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn transpose_diatonic(&self, degrees: i32, key: Key) -> Result<Note, PitchyError> {
+        let letter = self.letter.step(degrees);
+        // Widen so huge `degrees` report an overflow instead of panicking
+        let octave_shift = (self.letter.index() as i64 + degrees as i64).div_euclid(7);
+        let octave = i8::try_from(self.octave as i64 + octave_shift)
+            .map_err(|_| PitchyError::MidiOverflow)?;
+
+        Ok(Note::new(letter, key.accidental_for(letter)?, octave))
+    }
+
+    /// Returns the absolute chromatic index of the note: `(octave + 1) * 12 + letter + accidental`.
+    ///
+    /// This equals the MIDI number for in-range notes but is also defined outside the range.
+    /// Enharmonic spellings share an index, which makes it a stable key for hashing or
+    /// set membership by sounding pitch.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    /// let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    /// assert_eq!(c_sharp4.chromatic_index(), 61);
+    /// assert_eq!(d_flat4.chromatic_index(), 61);
+    /// ```
+    #[must_use]
+    pub fn chromatic_index(&self) -> i32 {
+        (self.octave as i32 + 1) * 12 + self.letter as i32 + self.accidental as i32
+    }
+
+    /// Encodes this note as a three-byte MIDI note-on message: `[0x90 | channel, note, velocity]`.
+    ///
+    /// `channel` is zero-based (0–15) and `velocity` must be at most 127.
+    ///
+    /// # Errors
+    /// Returns [`PitchyError::OutOfMidiRange`] if the note is outside the MIDI range, or
+    /// [`PitchyError::InvalidMidiMessage`] if `channel` or `velocity` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    /// assert_eq!(c4.to_note_on(0, 100).unwrap(), [0x90, 60, 100]);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn to_note_on(&self, channel: u8, velocity: u8) -> Result<[u8; 3], PitchyError> {
+        let midi = self.chromatic_index();
+        if !(0..=127).contains(&midi) {
+            return Err(PitchyError::OutOfMidiRange(midi as i16));
+        }
+        if channel > 15 || velocity > 127 {
+            return Err(PitchyError::InvalidMidiMessage);
+        }
+
+        Ok([0x90 | channel, midi as u8, velocity])
+    }
+
+    /// Decodes a MIDI note-on message into the note and its velocity.
+    ///
+    /// The channel is ignored. Black keys are spelled with sharps, as by
+    /// [`Note::try_from_midi_number`] with [`SpellingPreference::Sharp`]. A velocity of 0,
+    /// which many devices send instead of note-off, is returned as is.
+    ///
+    /// # Errors
+    /// Returns [`PitchyError::InvalidMidiMessage`] if the status byte is not a note-on
+    /// (`0x90`–`0x9F`) or a data byte has its high bit set.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let (note, velocity) = Note::from_note_on([0x93, 61, 64]).unwrap();
+    /// assert_eq!(note, Note::new(NoteLetter::C, Accidental::Sharp, 4));
+    /// assert_eq!(velocity, 64);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn from_note_on(bytes: [u8; 3]) -> Result<(Note, u8), PitchyError> {
+        let [status, midi, velocity] = bytes;
+        if status & 0xF0 != 0x90 || midi > 127 || velocity > 127 {
+            return Err(PitchyError::InvalidMidiMessage);
+        }
+
+        Ok((
+            Note::try_from_midi_number(midi, SpellingPreference::Sharp)?,
+            velocity,
+        ))
+    }
+
+    /// Returns the letter, accidental and octave as separate parts, e.g. `("C", "#", 4)`.
+    ///
+    /// The strings come from [`NoteLetter::as_str`] and [`Accidental::as_str`], so callers
+    /// can assemble a name in their own format without allocating.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter};
+    ///
+    /// let b_flat2 = Note::new(NoteLetter::B, Accidental::Flat, 2);
+    /// assert_eq!(b_flat2.as_parts(), ("B", "b", 2));
+    /// ```
+    #[must_use]
+    pub fn as_parts(&self) -> (&'static str, &'static str, i8) {
+        (self.letter.as_str(), self.accidental.as_str(), self.octave)
+    }
+
+    /// Writes the name of the note (e.g., "A4", "C#3") into any [`core::fmt::Write`] sink.
+    ///
+    /// Unlike [`Note::name`], this does not allocate and is available in `no_std` environments.
+    pub fn write_name(&self, f: &mut impl core::fmt::Write) -> core::fmt::Result {
+        write!(f, "{}{}{}", self.letter, self.accidental, self.octave)
+    }
+
     /// Returns the name of the note (e.g., "A4", "C#3") if possible.
     /// Only available when the `std` feature is enabled.
     #[cfg(feature = "std")]
+    #[must_use]
     pub fn name(&self) -> String {
-        format!("{}{}{}", self.letter, self.accidental, self.octave)
+        let mut name = String::new();
+        // Writing into a `String` never fails
+        let _ = self.write_name(&mut name);
+        name
+    }
+
+    /// Writes the name of the note with its octave numbered by `convention`.
+    ///
+    /// This is the allocation-free counterpart of [`Note::name_with_convention`].
+    pub fn write_name_with_convention(
+        &self,
+        f: &mut impl core::fmt::Write,
+        convention: OctaveConvention,
+    ) -> core::fmt::Result {
+        let octave = self.octave as i16 + convention.offset as i16;
+        write!(f, "{}{}{}", self.letter, self.accidental, octave)
+    }
+
+    /// Returns the name of the note with its octave numbered by `convention`.
+    /// Only available when the `std` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, OctaveConvention};
+    ///
+    /// let middle_c = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    /// assert_eq!(middle_c.name_with_convention(OctaveConvention::YAMAHA), "C3");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn name_with_convention(&self, convention: OctaveConvention) -> String {
+        let mut name = String::new();
+        // Writing into a `String` never fails
+        let _ = self.write_name_with_convention(&mut name, convention);
+        name
+    }
+
+    /// Parses a note name whose octave is numbered by `convention`, the inverse of
+    /// [`Note::name_with_convention`].
+    ///
+    /// Accepts the same letters and accidentals as [`Pitch`]'s `FromStr`. The range is not
+    /// checked; returns `PitchyError::InvalidOctave` if the standard octave does not fit an `i8`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, OctaveConvention};
+    ///
+    /// let note = Note::parse_with_convention("C3", OctaveConvention::YAMAHA).unwrap();
+    /// assert_eq!(note, Note::new(NoteLetter::C, Accidental::Natural, 4));
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn parse_with_convention(
+        s: &str,
+        convention: OctaveConvention,
+    ) -> Result<Note, PitchyError> {
+        let written = parse_note(s)?;
+        let octave = written
+            .octave
+            .checked_sub(convention.offset)
+            .ok_or(PitchyError::InvalidOctave)?;
+
+        Ok(Note::new(written.letter, written.accidental, octave))
+    }
+
+    /// Returns the note in Helmholtz pitch notation (e.g., `"C,"`, `"C"`, `"c"`, `"c'"`).
+    ///
+    /// Octave 3 and above use a lowercase letter with one prime (`'`) per octave above 3,
+    /// so C4 is `"c'"`. Octave 2 and below use an uppercase letter with one comma per
+    /// octave below 2, so C2 is `"C"` and C1 is `"C,"`. The accidental follows the letter.
+    /// Only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn helmholtz_name(&self) -> String {
+        let (letter, marks) = if self.octave >= 3 {
+            let primes = "'".repeat((self.octave - 3) as usize);
+            (self.letter.as_str().to_ascii_lowercase(), primes)
+        } else {
+            let commas = ",".repeat((2 - self.octave as i16) as usize);
+            (self.letter.as_str().to_owned(), commas)
+        };
+
+        format!("{}{}{}", letter, self.accidental, marks)
+    }
+
+    /// Returns the note as a VexFlow key string (e.g., `"c#/4"`, `"bb/5"`, `"f##/3"`).
+    /// Only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_vexflow(&self) -> String {
+        let accidental = match self.accidental {
+            Accidental::DoubleFlat => "bb",
+            Accidental::Flat => "b",
+            Accidental::Natural => "",
+            Accidental::Sharp => "#",
+            Accidental::DoubleSharp => "##",
+        };
+
+        format!(
+            "{}{}/{}",
+            self.letter.as_str().to_ascii_lowercase(),
+            accidental,
+            self.octave
+        )
+    }
+}
+
+/// Notes are ordered by staff position; see [`Note::cmp_by_staff`].
+/// Use [`Note::cmp_by_pitch`] to sort by sounding pitch instead.
+impl Ord for Note {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_by_staff(other)
+    }
+}
+
+impl PartialOrd for Note {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Defaults to A4, matching [`Pitch::default`].
+impl Default for Note {
+    fn default() -> Self {
+        Self::new(NoteLetter::A, Accidental::Natural, 4)
     }
 }
 
@@ -58,12 +879,13 @@ impl TryFrom<Pitch> for Note {
 
     /// Attempts to convert a [`Pitch`] into a symbolic [`Note`] using standard sharp-based spelling.
     ///
-    /// The conversion prefers natural and sharp spellings by default. Flat or double accidentals
-    /// are only used when required to accurately represent the pitch semitone.
+    /// The pitch is rounded to its nearest MIDI note, which is spelled as by
+    /// [`Note::try_from_midi_number`] with [`SpellingPreference::Sharp`].
+    /// The original spelling of a parsed pitch is not kept, so `"Gb5"` comes back as `F#5`;
+    /// use [`Pitch::into_note_preferring`] or [`Pitch::parse_as_note`] to control it.
     ///
     /// # Errors
-    /// Returns [`PitchyError::Unspelled`] if the pitch is outside the MIDI range or
-    /// cannot be represented by a valid letter and accidental.
+    /// Returns [`PitchyError::OutOfMidiRange`] if the pitch is outside the MIDI range.
     ///
     /// # Examples
     /// ```
@@ -76,26 +898,6 @@ impl TryFrom<Pitch> for Note {
     /// assert_eq!(note.name(), "A4");
     /// ```
     fn try_from(pitch: Pitch) -> Result<Self, Self::Error> {
-        let midi = pitch.try_midi_number()? as i8;
-        let octave = midi / 12 - 1;
-        let semitone = midi % 12;
-
-        // Use sharp-biased mapping: try natural & sharp-based letters first
-        for accidental in [
-            Accidental::Natural,
-            Accidental::Sharp,
-            Accidental::Flat,
-            Accidental::DoubleSharp,
-            Accidental::DoubleFlat,
-        ] {
-            for letter in NoteLetter::all() {
-                let base = letter as i8;
-                if base + accidental as i8 == semitone {
-                    return Ok(Note::new(letter, accidental, octave));
-                }
-            }
-        }
-
-        Err(PitchyError::Unspelled)
+        Note::try_from_midi_number(pitch.try_midi_number()?, SpellingPreference::Sharp)
     }
 }
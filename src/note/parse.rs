@@ -0,0 +1,86 @@
+//! Parsing of note name strings such as `"C#4"`, `"Db-1"` or `"F𝄪3"`.
+
+use core::str::FromStr;
+
+use crate::{Accidental, Note, NoteLetter, PitchyError};
+
+/// Parses a note name into its spelled [`Note`], without checking the MIDI range.
+///
+/// The name is a letter, an optional accidental (ASCII or Unicode glyph) and an octave,
+/// which may be negative (`"Cb-1"`). The letter and ASCII accidentals are case-insensitive.
+pub(crate) fn parse_note(s: &str) -> Result<Note, PitchyError> {
+    let s = s.trim();
+    let mut chars = s.chars();
+    let letter = chars.next().ok_or(PitchyError::InvalidName)?;
+    let letter = NoteLetter::try_from(letter)?;
+    let rest = chars.as_str();
+
+    let digits_index = rest
+        .find(|c: char| c.is_ascii_digit())
+        .ok_or(PitchyError::InvalidOctave)?;
+    // A minus is only an octave sign when it directly precedes the digits
+    let split_index = if rest[..digits_index].ends_with('-') {
+        digits_index - 1
+    } else {
+        digits_index
+    };
+    let (accidental_str, octave_str) = rest.split_at(split_index);
+    let accidental = parse_accidental(accidental_str)?;
+    let octave: i8 = octave_str.parse().map_err(|_| PitchyError::InvalidOctave)?;
+
+    Ok(Note::new(letter, accidental, octave))
+}
+
+/// Parses an octave-less note name such as `"C#"` or `"Bb"` into its letter and accidental.
+pub(crate) fn parse_pitch_class(s: &str) -> Result<(NoteLetter, Accidental), PitchyError> {
+    let s = s.trim();
+    let mut chars = s.chars();
+    let letter = chars.next().ok_or(PitchyError::InvalidName)?;
+    let letter = NoteLetter::try_from(letter)?;
+    let accidental = parse_accidental(chars.as_str())?;
+
+    Ok((letter, accidental))
+}
+
+/// Parses a note in Helmholtz notation (e.g. `"C,"`, `"c"`, `"f#''"`), the inverse of
+/// [`Note::helmholtz_name`].
+///
+/// A lowercase letter starts at octave 3 and each `'` raises it by one; an uppercase letter
+/// starts at octave 2 and each `,` lowers it by one. Primes on an uppercase letter or
+/// commas on a lowercase one are rejected.
+pub(crate) fn parse_helmholtz(s: &str) -> Result<Note, PitchyError> {
+    let s = s.trim();
+    let mut chars = s.chars();
+    let first = chars.next().ok_or(PitchyError::InvalidName)?;
+    let letter = NoteLetter::try_from(first)?;
+    let rest = chars.as_str();
+
+    let (mark, base_octave, step) = if first.is_ascii_lowercase() {
+        ('\'', 3, 1)
+    } else {
+        (',', 2, -1)
+    };
+
+    let marks_start = rest.find(['\'', ',']).unwrap_or(rest.len());
+    let (accidental_str, marks) = rest.split_at(marks_start);
+    if marks.chars().any(|c| c != mark) {
+        return Err(PitchyError::InvalidOctave);
+    }
+    let accidental = parse_accidental(accidental_str)?;
+
+    let octave = i8::try_from(base_octave + step * marks.len() as i64)
+        .map_err(|_| PitchyError::InvalidOctave)?;
+
+    Ok(Note::new(letter, accidental, octave))
+}
+
+/// Parses an accidental, accepting ASCII spellings in either case (e.g. `"B"` for flat).
+fn parse_accidental(s: &str) -> Result<Accidental, PitchyError> {
+    match Accidental::from_str(s) {
+        Ok(accidental) => Ok(accidental),
+        Err(_) if s.eq_ignore_ascii_case("b") => Ok(Accidental::Flat),
+        Err(_) if s.eq_ignore_ascii_case("bb") => Ok(Accidental::DoubleFlat),
+        Err(_) if s.eq_ignore_ascii_case("x") => Ok(Accidental::DoubleSharp),
+        Err(err) => Err(err.into()),
+    }
+}
@@ -2,8 +2,12 @@
 /// such as accidentals or letter mappings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SymbolError {
-    /// The semitone offset does not correspond to a valid [`Accidental`].
+    /// The semitone offset does not correspond to a valid [`Accidental`](crate::Accidental).
     InvalidAccidental(i8),
+    /// The string is not a recognized [`Accidental`](crate::Accidental) symbol.
+    InvalidAccidentalSymbol,
+    /// The character does not correspond to a valid [`NoteLetter`](crate::NoteLetter).
+    InvalidLetter(char),
     // More error variants may be added in the future
 }
 
@@ -13,6 +17,12 @@ impl core::fmt::Display for SymbolError {
             SymbolError::InvalidAccidental(i) => {
                 write!(f, "invalid semitone offset for accidental: {}", i)
             }
+            SymbolError::InvalidAccidentalSymbol => {
+                write!(f, "unrecognized accidental symbol")
+            }
+            SymbolError::InvalidLetter(c) => {
+                write!(f, "invalid note letter: {:?}", c)
+            }
         }
     }
 }
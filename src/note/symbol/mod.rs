@@ -2,7 +2,7 @@
 //!
 //! These types define the spelled representation of notes (e.g., `C#`, `Bb`, `F𝄪`).
 
-use error::SymbolError;
+pub use error::SymbolError;
 
 mod error;
 
@@ -30,6 +30,63 @@ impl NoteLetter {
         [C, D, E, F, G, A, B]
     }
 
+    /// Position of the letter in the diatonic sequence C, D, E, F, G, A, B (C = 0, B = 6).
+    pub(crate) const fn index(&self) -> i8 {
+        use NoteLetter::*;
+
+        match self {
+            C => 0,
+            D => 1,
+            E => 2,
+            F => 3,
+            G => 4,
+            A => 5,
+            B => 6,
+        }
+    }
+
+    /// Returns the next letter up, wrapping from B to C.
+    pub const fn next(&self) -> Self {
+        self.step(1)
+    }
+
+    /// Returns the next letter down, wrapping from C to B.
+    pub const fn prev(&self) -> Self {
+        self.step(-1)
+    }
+
+    /// Moves `n` letters up (or down, if negative), wrapping around the octave.
+    ///
+    /// # Examples
+    /// ```
+    /// use pitchy::NoteLetter;
+    ///
+    /// assert_eq!(NoteLetter::A.step(2), NoteLetter::C);
+    /// assert_eq!(NoteLetter::D.step(-8), NoteLetter::C);
+    /// ```
+    pub const fn step(&self, n: i32) -> Self {
+        // Reduce `n` first so extreme steps cannot overflow
+        Self::all()[(self.index() as i32 + n.rem_euclid(7)).rem_euclid(7) as usize]
+    }
+
+    /// Parses a note letter from an ASCII byte (`b'A'..=b'G'`, case-insensitive).
+    ///
+    /// A `const` counterpart to `TryFrom<char>` for `no_std` hot paths.
+    pub const fn from_ascii(c: u8) -> Result<Self, SymbolError> {
+        use NoteLetter::*;
+
+        match c.to_ascii_uppercase() {
+            b'C' => Ok(C),
+            b'D' => Ok(D),
+            b'E' => Ok(E),
+            b'F' => Ok(F),
+            b'G' => Ok(G),
+            b'A' => Ok(A),
+            b'B' => Ok(B),
+            _ => Err(SymbolError::InvalidLetter(c as char)),
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         use NoteLetter::*;
 
@@ -43,6 +100,21 @@ impl NoteLetter {
             B => "B",
         }
     }
+
+    /// Returns the letter as UTF-8 bytes, for rendering without `core::fmt`.
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        use NoteLetter::*;
+
+        match self {
+            C => b"C",
+            D => b"D",
+            E => b"E",
+            F => b"F",
+            G => b"G",
+            A => b"A",
+            B => b"B",
+        }
+    }
 }
 
 impl core::fmt::Display for NoteLetter {
@@ -51,6 +123,19 @@ impl core::fmt::Display for NoteLetter {
     }
 }
 
+impl TryFrom<char> for NoteLetter {
+    type Error = SymbolError;
+
+    /// Parses a note letter from `'A'..='G'`, case-insensitive.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if c.is_ascii() {
+            NoteLetter::from_ascii(c as u8)
+        } else {
+            Err(SymbolError::InvalidLetter(c))
+        }
+    }
+}
+
 /// Represents the accidental applied to a note (double flat, flat, natural, sharp, double sharp).
 ///
 /// The `repr(i8)` lets us treat accidentals as signed semitone offsets:
@@ -77,6 +162,21 @@ impl Accidental {
             DoubleFlat => "𝄫",
         }
     }
+
+    /// Returns the accidental symbol as UTF-8 bytes, for rendering without `core::fmt`.
+    ///
+    /// The double sharp and double flat glyphs are multi-byte.
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        use Accidental::*;
+
+        match self {
+            Natural => b"",
+            Sharp => b"#",
+            Flat => b"b",
+            DoubleSharp => "𝄪".as_bytes(),
+            DoubleFlat => "𝄫".as_bytes(),
+        }
+    }
 }
 
 impl core::fmt::Display for Accidental {
@@ -85,6 +185,25 @@ impl core::fmt::Display for Accidental {
     }
 }
 
+impl core::str::FromStr for Accidental {
+    type Err = SymbolError;
+
+    /// Parses an accidental from its ASCII or Unicode symbol.
+    ///
+    /// Accepts `""`/`"♮"` (natural), `"#"`/`"♯"`, `"b"`/`"♭"`, `"##"`/`"x"`/`"𝄪"`
+    /// and `"bb"`/`"𝄫"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" | "♮" => Ok(Accidental::Natural),
+            "#" | "♯" => Ok(Accidental::Sharp),
+            "b" | "♭" => Ok(Accidental::Flat),
+            "##" | "x" | "𝄪" => Ok(Accidental::DoubleSharp),
+            "bb" | "𝄫" => Ok(Accidental::DoubleFlat),
+            _ => Err(SymbolError::InvalidAccidentalSymbol),
+        }
+    }
+}
+
 impl TryFrom<i8> for Accidental {
     type Error = SymbolError;
 
@@ -159,3 +159,844 @@ fn test_try_from_pitch_to_note() {
         );
     }
 }
+
+#[test]
+fn test_write_name_into_fixed_buffer() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    struct Buffer {
+        bytes: [u8; 8],
+        len: usize,
+    }
+
+    impl core::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut buffer = Buffer {
+        bytes: [0; 8],
+        len: 0,
+    };
+    let note = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    note.write_name(&mut buffer).unwrap();
+
+    assert_eq!(&buffer.bytes[..buffer.len], b"C#4");
+}
+
+#[test]
+fn test_semitones_to() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    let e4 = Note::new(NoteLetter::E, Accidental::Natural, 4);
+    let g4 = Note::new(NoteLetter::G, Accidental::Natural, 4);
+    let b_sharp3 = Note::new(NoteLetter::B, Accidental::Sharp, 3);
+    let c_low = Note::new(NoteLetter::C, Accidental::Natural, -3);
+
+    assert_eq!(c4.semitones_to(&g4), 7);
+    assert_eq!(e4.semitones_to(&c4), -4);
+    assert_eq!(b_sharp3.semitones_to(&c4), 0);
+    // Works outside the MIDI range
+    assert_eq!(c_low.semitones_to(&c4), 84);
+}
+
+#[test]
+fn test_note_letter_from_char() {
+    use crate::{NoteLetter, SymbolError};
+
+    assert_eq!(NoteLetter::try_from('C'), Ok(NoteLetter::C));
+    assert_eq!(NoteLetter::try_from('c'), Ok(NoteLetter::C));
+    assert_eq!(NoteLetter::try_from('g'), Ok(NoteLetter::G));
+    assert_eq!(
+        NoteLetter::try_from('H'),
+        Err(SymbolError::InvalidLetter('H'))
+    );
+    assert_eq!(
+        NoteLetter::try_from('♯'),
+        Err(SymbolError::InvalidLetter('♯'))
+    );
+
+    const A: Result<NoteLetter, SymbolError> = NoteLetter::from_ascii(b'a');
+    assert_eq!(A, Ok(NoteLetter::A));
+    assert!(NoteLetter::from_ascii(b'h').is_err());
+}
+
+#[test]
+fn test_enharmonic_equivalents_within() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    assert!(
+        c4.enharmonic_equivalents_within(Accidental::Flat)
+            .eq([c4, Note::new(NoteLetter::B, Accidental::Sharp, 3)])
+    );
+    assert!(
+        c4.enharmonic_equivalents_within(Accidental::DoubleSharp)
+            .eq([
+                c4,
+                Note::new(NoteLetter::B, Accidental::Sharp, 3),
+                Note::new(NoteLetter::D, Accidental::DoubleFlat, 4),
+            ])
+    );
+
+    let g_sharp4 = Note::new(NoteLetter::G, Accidental::Sharp, 4);
+    assert!(
+        g_sharp4
+            .enharmonic_equivalents_within(Accidental::Natural)
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_accidental_from_str() {
+    use crate::{Accidental, SymbolError};
+    use core::str::FromStr;
+
+    let datasets = [
+        ("", Accidental::Natural),
+        ("♮", Accidental::Natural),
+        ("#", Accidental::Sharp),
+        ("♯", Accidental::Sharp),
+        ("b", Accidental::Flat),
+        ("♭", Accidental::Flat),
+        ("##", Accidental::DoubleSharp),
+        ("x", Accidental::DoubleSharp),
+        ("𝄪", Accidental::DoubleSharp),
+        ("bb", Accidental::DoubleFlat),
+        ("𝄫", Accidental::DoubleFlat),
+    ];
+
+    for (symbol, expected) in datasets {
+        assert_eq!(Accidental::from_str(symbol), Ok(expected), "{symbol:?}");
+    }
+
+    for symbol in ["###", "B", "♯♯", " #"] {
+        assert_eq!(
+            Accidental::from_str(symbol),
+            Err(SymbolError::InvalidAccidentalSymbol),
+            "{symbol:?}"
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_to_vexflow() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let datasets = [
+        (NoteLetter::C, Accidental::Sharp, 4, "c#/4"),
+        (NoteLetter::B, Accidental::Flat, 5, "bb/5"),
+        (NoteLetter::F, Accidental::DoubleSharp, 3, "f##/3"),
+        (NoteLetter::E, Accidental::DoubleFlat, 2, "ebb/2"),
+        (NoteLetter::A, Accidental::Natural, -1, "a/-1"),
+    ];
+
+    for (letter, accidental, octave, expected) in datasets {
+        let note = Note::new(letter, accidental, octave);
+        assert_eq!(note.to_vexflow(), expected);
+    }
+}
+
+#[test]
+fn test_symbol_error_into_pitchy_error() {
+    use crate::{Accidental, NoteLetter, PitchyError};
+    use core::str::FromStr;
+
+    fn parse(letter: char, accidental: &str) -> Result<(NoteLetter, Accidental), PitchyError> {
+        Ok((
+            NoteLetter::try_from(letter)?,
+            Accidental::from_str(accidental)?,
+        ))
+    }
+
+    assert_eq!(parse('D', "b"), Ok((NoteLetter::D, Accidental::Flat)));
+    assert_eq!(parse('H', "#"), Err(PitchyError::InvalidName));
+    assert_eq!(parse('C', "?"), Err(PitchyError::InvalidName));
+    assert_eq!(
+        PitchyError::from(Accidental::try_from(3).unwrap_err()),
+        PitchyError::InvalidName
+    );
+}
+
+#[test]
+fn test_symbol_as_bytes() {
+    use crate::{Accidental, NoteLetter};
+
+    for letter in NoteLetter::all() {
+        assert_eq!(letter.as_bytes(), letter.as_str().as_bytes());
+    }
+
+    for accidental in [
+        Accidental::DoubleFlat,
+        Accidental::Flat,
+        Accidental::Natural,
+        Accidental::Sharp,
+        Accidental::DoubleSharp,
+    ] {
+        assert_eq!(accidental.as_bytes(), accidental.as_str().as_bytes());
+    }
+
+    assert_eq!(Accidental::DoubleSharp.as_bytes(), [0xF0, 0x9D, 0x84, 0xAA]);
+}
+
+#[test]
+fn test_solfege() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let datasets = [
+        (
+            NoteLetter::C,
+            Accidental::Natural,
+            NoteLetter::C,
+            Some("Do"),
+        ),
+        (
+            NoteLetter::E,
+            Accidental::Natural,
+            NoteLetter::C,
+            Some("Mi"),
+        ),
+        (NoteLetter::F, Accidental::Sharp, NoteLetter::C, Some("Fi")),
+        (NoteLetter::B, Accidental::Flat, NoteLetter::C, Some("Te")),
+        (NoteLetter::D, Accidental::Flat, NoteLetter::C, Some("Ra")),
+        (
+            NoteLetter::G,
+            Accidental::Natural,
+            NoteLetter::C,
+            Some("Sol"),
+        ),
+        (NoteLetter::E, Accidental::Sharp, NoteLetter::C, None),
+        (NoteLetter::C, Accidental::DoubleSharp, NoteLetter::C, None),
+        // Movable do in G major: F# is the leading tone
+        (NoteLetter::F, Accidental::Sharp, NoteLetter::G, Some("Ti")),
+        (
+            NoteLetter::F,
+            Accidental::Natural,
+            NoteLetter::G,
+            Some("Te"),
+        ),
+        (
+            NoteLetter::D,
+            Accidental::Natural,
+            NoteLetter::G,
+            Some("Sol"),
+        ),
+        // In F major, Bb is the fourth degree
+        (NoteLetter::B, Accidental::Flat, NoteLetter::F, Some("Fa")),
+    ];
+
+    for (letter, accidental, tonic, expected) in datasets {
+        let note = Note::new(letter, accidental, 4);
+        assert_eq!(
+            note.solfege(tonic),
+            expected,
+            "{letter}{accidental} in {tonic}"
+        );
+    }
+
+    let f_sharp = Note::new(NoteLetter::F, Accidental::Sharp, 2);
+    assert_eq!(f_sharp.solfege_fixed(), Some("Fi"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_helmholtz_name() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let datasets = [
+        (NoteLetter::C, Accidental::Natural, 4, "c'"),
+        (NoteLetter::C, Accidental::Natural, 2, "C"),
+        (NoteLetter::C, Accidental::Natural, 3, "c"),
+        (NoteLetter::B, Accidental::Natural, 2, "B"),
+        (NoteLetter::A, Accidental::Natural, 0, "A,,"),
+        (NoteLetter::C, Accidental::Natural, -1, "C,,,"),
+        (NoteLetter::F, Accidental::Sharp, 5, "f#''"),
+        (NoteLetter::E, Accidental::Flat, 1, "Eb,"),
+    ];
+
+    for (letter, accidental, octave, expected) in datasets {
+        let note = Note::new(letter, accidental, octave);
+        assert_eq!(note.helmholtz_name(), expected);
+    }
+}
+
+#[test]
+fn test_note_comparators() {
+    use crate::{Accidental, Note, NoteLetter};
+    use core::cmp::Ordering;
+
+    let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    assert_eq!(c_sharp4.cmp_by_pitch(&d_flat4), Ordering::Greater);
+    assert_eq!(c_sharp4.cmp_by_staff(&d_flat4), Ordering::Less);
+    assert_eq!(c_sharp4.cmp(&d_flat4), c_sharp4.cmp_by_staff(&d_flat4));
+
+    let b_sharp3 = Note::new(NoteLetter::B, Accidental::Sharp, 3);
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    let d4 = Note::new(NoteLetter::D, Accidental::Natural, 4);
+    assert_eq!(b_sharp3.cmp_by_pitch(&c4), Ordering::Greater);
+    assert_eq!(b_sharp3.cmp_by_staff(&c4), Ordering::Less);
+
+    for (a, b) in [(c4, d4), (c_sharp4, c_sharp4)] {
+        assert_eq!(a.cmp_by_pitch(&b), a.cmp_by_staff(&b));
+    }
+
+    let mut notes = [d4, d_flat4, c_sharp4, c4, b_sharp3];
+    notes.sort();
+    assert_eq!(notes, [b_sharp3, c4, c_sharp4, d_flat4, d4]);
+    notes.sort_by(Note::cmp_by_pitch);
+    assert_eq!(notes, [c4, b_sharp3, d_flat4, c_sharp4, d4]);
+}
+
+#[test]
+fn test_midi_note_names() {
+    use crate::{MIDI_NOTE_NAMES, midi_note_name};
+
+    assert_eq!(MIDI_NOTE_NAMES[0], "C-1");
+    assert_eq!(MIDI_NOTE_NAMES[60], "C4");
+    assert_eq!(MIDI_NOTE_NAMES[69], "A4");
+    assert_eq!(MIDI_NOTE_NAMES[127], "G9");
+    assert_eq!(midi_note_name(70), Some("A#4"));
+    assert_eq!(midi_note_name(255), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_midi_note_names_match_note_spelling() {
+    use crate::{MIDI_NOTE_NAMES, Note, Pitch};
+
+    for (midi, expected) in (0..=127).zip(MIDI_NOTE_NAMES) {
+        let note = Note::try_from(Pitch::try_from_midi_number(midi).unwrap()).unwrap();
+        assert_eq!(note.name(), expected);
+    }
+}
+
+#[test]
+fn test_sharpen_and_flatten() {
+    use crate::{Accidental, Note, NoteLetter, SymbolError};
+
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    let c_sharp4 = c4.sharpen().unwrap();
+    let c_double_sharp4 = c_sharp4.sharpen().unwrap();
+    assert_eq!(c_sharp4, Note::new(NoteLetter::C, Accidental::Sharp, 4));
+    assert_eq!(
+        c_double_sharp4,
+        Note::new(NoteLetter::C, Accidental::DoubleSharp, 4)
+    );
+    assert_eq!(
+        c_double_sharp4.sharpen(),
+        Err(SymbolError::InvalidAccidental(3))
+    );
+
+    let c_double_flat4 = c4.flatten().unwrap().flatten().unwrap();
+    assert_eq!(c_double_flat4.accidental(), Accidental::DoubleFlat);
+    assert_eq!(
+        c_double_flat4.flatten(),
+        Err(SymbolError::InvalidAccidental(-3))
+    );
+
+    assert_eq!(c_sharp4.flatten(), Ok(c4));
+}
+
+#[test]
+fn test_try_new_checks_midi_range() {
+    use crate::{Accidental, Note, NoteLetter, PitchyError};
+
+    assert_eq!(
+        Note::try_new(NoteLetter::C, Accidental::Natural, 4),
+        Ok(Note::new(NoteLetter::C, Accidental::Natural, 4))
+    );
+    assert_eq!(
+        Note::try_new(NoteLetter::C, Accidental::Natural, 10),
+        Err(PitchyError::OutOfMidiRange(132))
+    );
+
+    // The range edges depend on the accidental, not just the octave
+    assert!(Note::try_new(NoteLetter::C, Accidental::Natural, -1).is_ok());
+    assert!(Note::try_new(NoteLetter::G, Accidental::Natural, 9).is_ok());
+    assert_eq!(
+        Note::try_new(NoteLetter::C, Accidental::Flat, -1),
+        Err(PitchyError::OutOfMidiRange(-1))
+    );
+    assert_eq!(
+        Note::try_new(NoteLetter::G, Accidental::Sharp, 9),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+}
+
+#[test]
+fn test_try_from_midi_number() {
+    use crate::{Accidental, Note, NoteLetter, Pitch, PitchyError, SpellingPreference};
+
+    assert_eq!(
+        Note::try_from_midi_number(61, SpellingPreference::Sharp),
+        Ok(Note::new(NoteLetter::C, Accidental::Sharp, 4))
+    );
+    assert_eq!(
+        Note::try_from_midi_number(61, SpellingPreference::Flat),
+        Ok(Note::new(NoteLetter::D, Accidental::Flat, 4))
+    );
+    assert_eq!(
+        Note::try_from_midi_number(0, SpellingPreference::Flat),
+        Ok(Note::new(NoteLetter::C, Accidental::Natural, -1))
+    );
+    assert_eq!(
+        Note::try_from_midi_number(128, SpellingPreference::Sharp),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+
+    for midi in 0..=127 {
+        let sharp = Note::try_from_midi_number(midi, SpellingPreference::Sharp).unwrap();
+        let flat = Note::try_from_midi_number(midi, SpellingPreference::Flat).unwrap();
+        let pitch = Pitch::try_from_midi_number(midi).unwrap();
+
+        assert_eq!(Note::try_from(pitch), Ok(sharp));
+        assert_eq!(sharp.semitones_to(&flat), 0, "MIDI {midi}");
+        assert_eq!(
+            sharp == flat,
+            sharp.accidental() == Accidental::Natural,
+            "MIDI {midi}"
+        );
+    }
+}
+
+#[test]
+fn test_chromatic_index() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    assert_eq!(c_sharp4.chromatic_index(), 61);
+    assert_eq!(d_flat4.chromatic_index(), c_sharp4.chromatic_index());
+
+    // Defined outside the MIDI range too
+    assert_eq!(
+        Note::new(NoteLetter::C, Accidental::Flat, -1).chromatic_index(),
+        -1
+    );
+    assert_eq!(
+        Note::new(NoteLetter::C, Accidental::Natural, 10).chromatic_index(),
+        132
+    );
+    assert_eq!(
+        Note::new(NoteLetter::B, Accidental::Sharp, 3).chromatic_index(),
+        60
+    );
+}
+
+#[test]
+fn test_as_parts_composes_name_without_allocation() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let datasets = [
+        (NoteLetter::C, Accidental::Sharp, 4, "C#4"),
+        (NoteLetter::B, Accidental::Flat, 2, "Bb2"),
+        (NoteLetter::A, Accidental::Natural, -1, "A-1"),
+    ];
+
+    for (letter, accidental, octave, expected) in datasets {
+        let (letter_str, accidental_str, octave) = Note::new(letter, accidental, octave).as_parts();
+
+        let mut buffer = [0u8; 8];
+        let mut len = 0;
+        for part in [letter_str.as_bytes(), accidental_str.as_bytes()] {
+            buffer[len..len + part.len()].copy_from_slice(part);
+            len += part.len();
+        }
+        if octave < 0 {
+            buffer[len] = b'-';
+            len += 1;
+        }
+        buffer[len] = b'0' + octave.unsigned_abs();
+        len += 1;
+
+        assert_eq!(&buffer[..len], expected.as_bytes());
+    }
+}
+
+#[test]
+fn test_is_enharmonic() {
+    use crate::{Accidental, Note, NoteLetter};
+    use core::cmp::Ordering;
+
+    let c_sharp4 = Note::new(NoteLetter::C, Accidental::Sharp, 4);
+    let d_flat4 = Note::new(NoteLetter::D, Accidental::Flat, 4);
+    assert!(c_sharp4.is_enharmonic(&d_flat4));
+    assert_ne!(c_sharp4, d_flat4);
+    assert_ne!(c_sharp4.cmp_by_pitch(&d_flat4), Ordering::Equal);
+
+    let b_sharp3 = Note::new(NoteLetter::B, Accidental::Sharp, 3);
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    let d_double_flat4 = Note::new(NoteLetter::D, Accidental::DoubleFlat, 4);
+    assert!(b_sharp3.is_enharmonic(&c4));
+    assert!(d_double_flat4.is_enharmonic(&b_sharp3));
+    assert!(c4.is_enharmonic(&c4));
+
+    let c5 = Note::new(NoteLetter::C, Accidental::Natural, 5);
+    assert!(!c4.is_enharmonic(&c5));
+    assert!(!c4.is_enharmonic(&c_sharp4));
+}
+
+#[test]
+fn test_circle_of_fifths() {
+    use crate::{Accidental, Note, NoteLetter, PitchyError, circle_of_fifths, fifths_from};
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let circle = circle_of_fifths();
+    let first_six = [C, G, D, A, E, B].map(|letter| Note::new(letter, Natural, 4));
+    assert_eq!(circle[..6], first_six);
+    assert_eq!(circle[6], Note::new(F, Sharp, 4));
+    assert_eq!(circle[11], Note::new(F, Natural, 4));
+
+    // Neighbours are a fifth apart, modulo the octave
+    for (index, note) in circle.iter().enumerate() {
+        let next = circle[(index + 1) % 12];
+        assert_eq!(note.semitones_to(&next).rem_euclid(12), 7, "{note:?}");
+    }
+
+    let c4 = Note::new(C, Natural, 4);
+    assert!(fifths_from(c4, 6).map(Result::unwrap).eq(first_six));
+
+    let sharps = [F, C, G, D, A, E, B].map(|letter| Note::new(letter, Sharp, 4));
+    assert!(fifths_from(c4, 13).skip(6).map(Result::unwrap).eq(sharps));
+    assert_eq!(fifths_from(c4, 0).count(), 0);
+
+    let mut far = fifths_from(Note::new(B, DoubleSharp, 4), 5);
+    assert_eq!(far.next(), Some(Ok(Note::new(B, DoubleSharp, 4))));
+    assert_eq!(far.next(), Some(Err(PitchyError::Unspelled)));
+    assert_eq!(far.next(), None);
+}
+
+#[test]
+fn test_note_letter_stepping() {
+    use crate::NoteLetter::{self, *};
+
+    assert_eq!(B.next(), C);
+    assert_eq!(C.prev(), B);
+    assert_eq!(E.next(), F);
+    assert_eq!(F.prev(), E);
+
+    for letter in NoteLetter::all() {
+        assert_eq!(letter.next().prev(), letter);
+        assert_eq!(letter.step(7), letter);
+        assert_eq!(letter.step(-14), letter);
+        assert_eq!(letter.step(0), letter);
+    }
+    assert_eq!(C.step(4), G);
+    assert_eq!(G.step(-4), C);
+    assert_eq!(B.step(9), D);
+
+    // Extreme steps wrap without overflowing: i32::MAX ≡ 1 and i32::MIN ≡ 5 (mod 7)
+    assert_eq!(B.step(i32::MAX), C);
+    assert_eq!(C.step(i32::MAX), D);
+    assert_eq!(C.step(i32::MIN), A);
+    assert_eq!(B.step(i32::MIN), G);
+}
+
+#[test]
+fn test_transpose_diatonic() {
+    use crate::{Accidental, Key, Note, NoteLetter, PitchyError};
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let c_major = Key::major(C, Natural);
+    let g_major = Key::major(G, Natural);
+
+    let datasets = [
+        // Thirds and fifths in C major
+        (
+            c_major,
+            Note::new(C, Natural, 4),
+            2,
+            Note::new(E, Natural, 4),
+        ),
+        (
+            c_major,
+            Note::new(C, Natural, 4),
+            4,
+            Note::new(G, Natural, 4),
+        ),
+        (
+            c_major,
+            Note::new(A, Natural, 4),
+            2,
+            Note::new(C, Natural, 5),
+        ),
+        (
+            c_major,
+            Note::new(G, Natural, 4),
+            4,
+            Note::new(D, Natural, 5),
+        ),
+        (
+            c_major,
+            Note::new(E, Natural, 4),
+            -2,
+            Note::new(C, Natural, 4),
+        ),
+        (
+            c_major,
+            Note::new(C, Natural, 4),
+            -4,
+            Note::new(F, Natural, 3),
+        ),
+        // Thirds and fifths in G major
+        (
+            g_major,
+            Note::new(G, Natural, 3),
+            2,
+            Note::new(B, Natural, 3),
+        ),
+        (g_major, Note::new(D, Natural, 4), 2, Note::new(F, Sharp, 4)),
+        (g_major, Note::new(B, Natural, 3), 4, Note::new(F, Sharp, 4)),
+        (
+            g_major,
+            Note::new(G, Natural, 4),
+            4,
+            Note::new(D, Natural, 5),
+        ),
+        (g_major, Note::new(F, Sharp, 4), 7, Note::new(F, Sharp, 5)),
+        // Out-of-key input is pulled into the key
+        (c_major, Note::new(C, Sharp, 4), 2, Note::new(E, Natural, 4)),
+    ];
+
+    for (key, note, degrees, expected) in datasets {
+        assert_eq!(
+            note.transpose_diatonic(degrees, key),
+            Ok(expected),
+            "{note:?} + {degrees}"
+        );
+    }
+
+    let top = Note::new(B, Natural, 127);
+    assert_eq!(
+        top.transpose_diatonic(1, c_major),
+        Err(PitchyError::MidiOverflow)
+    );
+    for degrees in [i32::MAX, i32::MIN] {
+        assert_eq!(
+            Note::new(C, Natural, 4).transpose_diatonic(degrees, c_major),
+            Err(PitchyError::MidiOverflow),
+            "{degrees}"
+        );
+    }
+}
+
+#[test]
+fn test_note_on_round_trip() {
+    use crate::{Accidental, Note, NoteLetter, PitchyError};
+
+    let c4 = Note::new(NoteLetter::C, Accidental::Natural, 4);
+    let bytes = c4.to_note_on(0, 100).unwrap();
+    assert_eq!(bytes, [0x90, 60, 100]);
+    assert_eq!(Note::from_note_on(bytes), Ok((c4, 100)));
+
+    let bytes = c4.to_note_on(15, 0).unwrap();
+    assert_eq!(bytes, [0x9F, 60, 0]);
+    assert_eq!(Note::from_note_on(bytes), Ok((c4, 0)));
+
+    assert_eq!(c4.to_note_on(16, 100), Err(PitchyError::InvalidMidiMessage));
+    assert_eq!(c4.to_note_on(0, 128), Err(PitchyError::InvalidMidiMessage));
+    assert_eq!(
+        Note::new(NoteLetter::C, Accidental::Flat, -1).to_note_on(0, 100),
+        Err(PitchyError::OutOfMidiRange(-1))
+    );
+
+    // Note-off and data bytes with the high bit set
+    assert_eq!(
+        Note::from_note_on([0x80, 60, 100]),
+        Err(PitchyError::InvalidMidiMessage)
+    );
+    assert_eq!(
+        Note::from_note_on([0x90, 128, 100]),
+        Err(PitchyError::InvalidMidiMessage)
+    );
+    assert_eq!(
+        Note::from_note_on([0x90, 60, 200]),
+        Err(PitchyError::InvalidMidiMessage)
+    );
+}
+
+#[test]
+fn test_effective_octave() {
+    use crate::{Accidental, Note, NoteLetter};
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let datasets = [
+        (Note::new(C, Flat, 4), 3),
+        (Note::new(C, DoubleFlat, 4), 3),
+        (Note::new(C, Natural, 4), 4),
+        (Note::new(B, Natural, 4), 4),
+        (Note::new(B, Sharp, 4), 5),
+        (Note::new(B, DoubleSharp, 4), 5),
+        (Note::new(A, DoubleSharp, 4), 4),
+        (Note::new(C, Flat, -1), -2),
+        (Note::new(B, Sharp, 127), 127),
+    ];
+
+    for (note, expected) in datasets {
+        assert_eq!(note.effective_octave(), expected, "{note:?}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_octave_convention() {
+    use crate::{Accidental, Note, NoteLetter, OctaveConvention, PitchyError, SpellingPreference};
+
+    let middle_c = Note::try_from_midi_number(60, SpellingPreference::Sharp).unwrap();
+    assert_eq!(
+        middle_c.name_with_convention(OctaveConvention::YAMAHA),
+        "C3"
+    );
+    assert_eq!(
+        middle_c.name_with_convention(OctaveConvention::default()),
+        "C4"
+    );
+    assert_eq!(OctaveConvention::default(), OctaveConvention::SCIENTIFIC);
+
+    let lowest = Note::try_from_midi_number(0, SpellingPreference::Sharp).unwrap();
+    assert_eq!(lowest.name_with_convention(OctaveConvention::YAMAHA), "C-2");
+
+    let datasets = [
+        ("C3", OctaveConvention::YAMAHA, middle_c),
+        ("C4", OctaveConvention::SCIENTIFIC, middle_c),
+        ("C-2", OctaveConvention::YAMAHA, lowest),
+        (
+            "Bb5",
+            OctaveConvention { offset: 1 },
+            Note::new(NoteLetter::B, Accidental::Flat, 4),
+        ),
+    ];
+
+    for (name, convention, expected) in datasets {
+        let note = Note::parse_with_convention(name, convention).unwrap();
+        assert_eq!(note, expected, "{name}");
+        assert_eq!(note.name_with_convention(convention), name);
+    }
+
+    assert_eq!(
+        Note::parse_with_convention("C127", OctaveConvention::YAMAHA),
+        Err(PitchyError::InvalidOctave)
+    );
+}
+
+#[test]
+fn test_note_is_natural() {
+    use crate::{Accidental, Note, NoteLetter};
+    use Accidental::*;
+    use NoteLetter::*;
+
+    assert!(Note::new(C, Natural, 4).is_natural());
+    assert!(!Note::new(C, Sharp, 4).is_natural());
+    assert!(!Note::new(C, Flat, 4).is_natural());
+    assert!(!Note::new(E, Sharp, 4).is_natural());
+    assert!(!Note::new(D, DoubleFlat, 4).is_natural());
+}
+
+#[test]
+fn test_octave_shifting() {
+    use crate::{Accidental, Note, NoteLetter};
+    use Accidental::*;
+    use NoteLetter::*;
+
+    let d_flat4 = Note::new(D, Flat, 4);
+    assert_eq!(d_flat4.shifted_octave(1), Note::new(D, Flat, 5));
+    assert_eq!(d_flat4.shifted_octave(-5), Note::new(D, Flat, -1));
+    assert_eq!(d_flat4.shifted_octave(0), d_flat4);
+    assert_eq!(d_flat4.with_octave(2), Note::new(D, Flat, 2));
+
+    // Spelling is kept even across the B–C boundary
+    let b_sharp3 = Note::new(B, Sharp, 3);
+    assert_eq!(b_sharp3.shifted_octave(1), Note::new(B, Sharp, 4));
+
+    assert_eq!(
+        Note::new(C, Natural, 120).shifted_octave(10),
+        Note::new(C, Natural, i8::MAX)
+    );
+    assert_eq!(
+        Note::new(C, Natural, -120).shifted_octave(-10),
+        Note::new(C, Natural, i8::MIN)
+    );
+}
+
+#[test]
+fn test_parse_note_and_pitch() {
+    use crate::{Accidental, Note, NoteLetter, Pitch, PitchyError};
+
+    let (note, pitch) = crate::parse("Db4").unwrap();
+    assert_eq!(note, Note::new(NoteLetter::D, Accidental::Flat, 4));
+    assert!((pitch.frequency() - 277.18).abs() < 0.01);
+    assert_eq!(pitch, Pitch::try_from_midi_number(61).unwrap());
+
+    let (note, _) = crate::parse("C#4").unwrap();
+    assert_eq!(note.accidental(), Accidental::Sharp);
+
+    assert_eq!(crate::parse("H4"), Err(PitchyError::InvalidName));
+    assert_eq!(crate::parse("A9"), Err(PitchyError::OutOfMidiRange(129)));
+}
+
+#[test]
+fn test_parse_negative_octaves() {
+    use crate::{Accidental, Note, NoteLetter, Pitch, PitchyError, note::parse_note};
+    use core::str::FromStr;
+
+    let datasets = [
+        ("Cb-1", Note::new(NoteLetter::C, Accidental::Flat, -1)),
+        ("B#-1", Note::new(NoteLetter::B, Accidental::Sharp, -1)),
+        ("C-1", Note::new(NoteLetter::C, Accidental::Natural, -1)),
+        ("D𝄫-1", Note::new(NoteLetter::D, Accidental::DoubleFlat, -1)),
+        (
+            "Ebb-12",
+            Note::new(NoteLetter::E, Accidental::DoubleFlat, -12),
+        ),
+    ];
+
+    for (name, expected) in datasets {
+        assert_eq!(parse_note(name), Ok(expected), "{name}");
+    }
+
+    // Cb-1 sounds a semitone below MIDI 0; B#-1 wraps up to C0
+    assert_eq!(
+        Pitch::from_str("Cb-1"),
+        Err(PitchyError::OutOfMidiRange(-1))
+    );
+    assert_eq!(Pitch::from_str("B#-1"), Pitch::try_from_midi_number(12));
+
+    assert_eq!(parse_note("-C4"), Err(PitchyError::InvalidName));
+    assert_eq!(parse_note("-1"), Err(PitchyError::InvalidName));
+    assert_eq!(parse_note("C-"), Err(PitchyError::InvalidOctave));
+    assert_eq!(parse_note("C-b4"), Err(PitchyError::InvalidName));
+    assert_eq!(parse_note("C--1"), Err(PitchyError::InvalidName));
+}
+
+#[test]
+fn test_nearest_note_str() {
+    use crate::{Pitch, nearest_note_str};
+
+    assert_eq!(nearest_note_str(&Pitch::new(445.0)), Some(("A", 4)));
+    assert_eq!(nearest_note_str(&Pitch::new(440.0)), Some(("A", 4)));
+    assert_eq!(nearest_note_str(&Pitch::new(277.18)), Some(("C#", 4)));
+
+    let lowest = Pitch::try_from_midi_number(0).unwrap();
+    assert_eq!(nearest_note_str(&lowest), Some(("C", -1)));
+    let lowest_sharp = Pitch::try_from_midi_number(1).unwrap();
+    assert_eq!(nearest_note_str(&lowest_sharp), Some(("C#", -1)));
+    let highest = Pitch::try_from_midi_number(127).unwrap();
+    assert_eq!(nearest_note_str(&highest), Some(("G", 9)));
+
+    assert_eq!(nearest_note_str(&Pitch::new(5.0)), None);
+    assert_eq!(nearest_note_str(&Pitch::new(20000.0)), None);
+}
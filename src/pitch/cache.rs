@@ -0,0 +1,60 @@
+//! An opt-in cache for repeatedly parsed note names.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::str::FromStr;
+
+use crate::{Pitch, PitchyError};
+
+/// Caches the results of parsing note name strings into [`Pitch`] values.
+///
+/// Useful when the same note names are parsed over and over (e.g., a frequently
+/// reloaded config). Only successful parses are cached; errors are returned as-is.
+/// Only available when the opt-in `alloc` feature is enabled; `std` is not required.
+#[derive(Debug, Clone, Default)]
+pub struct PitchCache {
+    entries: BTreeMap<String, Pitch>,
+}
+
+impl PitchCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached pitch for `s`, parsing and caching it on first use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::PitchCache;
+    ///
+    /// let mut cache = PitchCache::new();
+    /// let a4 = cache.get_or_parse("A4").unwrap();
+    /// assert_eq!(a4.frequency(), 440.0);
+    /// ```
+    pub fn get_or_parse(&mut self, s: &str) -> Result<Pitch, PitchyError> {
+        if let Some(pitch) = self.entries.get(s) {
+            return Ok(*pitch);
+        }
+
+        let pitch = Pitch::from_str(s)?;
+        self.entries.insert(String::from(s), pitch);
+        Ok(pitch)
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,14 @@
+//! Nearest-note readings for pitch detectors and tuner displays.
+
+/// The nearest MIDI note to a detected pitch, as returned by
+/// [`Pitch::detect_note`](crate::Pitch::detect_note).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoteDetection {
+    /// The nearest MIDI note number (0–127).
+    pub note: u8,
+    /// The signed deviation from `note` in cents, in \[−50, +50).
+    pub cents: f64,
+    /// How close the pitch is to `note`: `1.0 - cents.abs() / 50.0`, from 1.0 when dead
+    /// on down to 0.0 at the quarter-tone midpoint.
+    pub confidence: f64,
+}
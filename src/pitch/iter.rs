@@ -0,0 +1,51 @@
+//! Lazy transposition of pitch sequences.
+
+use crate::{Pitch, math::*};
+
+/// Extension methods for iterators over [`Pitch`] values.
+pub trait PitchIterExt: Iterator<Item = Pitch> + Sized {
+    /// Lazily transposes every pitch by the given number of semitones.
+    ///
+    /// Like [`transpose_all`](crate::transpose_all), the ratio is computed once for
+    /// the whole sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Pitch, PitchIterExt};
+    ///
+    /// let phrase = [Pitch::new(220.0), Pitch::new(330.0)];
+    /// let mut higher = phrase.into_iter().transpose(12.0);
+    /// assert_eq!(higher.next(), Some(Pitch::new(440.0)));
+    /// assert_eq!(higher.next(), Some(Pitch::new(660.0)));
+    /// ```
+    fn transpose(self, semitones: f64) -> Transposed<Self> {
+        Transposed {
+            iter: self,
+            ratio: powf2(semitones / 12.0),
+        }
+    }
+}
+
+impl<I: Iterator<Item = Pitch>> PitchIterExt for I {}
+
+/// Iterator returned by [`PitchIterExt::transpose`].
+#[derive(Debug, Clone)]
+pub struct Transposed<I> {
+    iter: I,
+    ratio: f64,
+}
+
+impl<I: Iterator<Item = Pitch>> Iterator for Transposed<I> {
+    type Item = Pitch;
+
+    fn next(&mut self) -> Option<Pitch> {
+        self.iter
+            .next()
+            .map(|pitch| Pitch::new(pitch.frequency() * self.ratio))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -4,19 +4,56 @@
 //!
 //! Useful for audio engines, synthesizers, or any application that needs to translate between symbolic notes and actual sound.
 //! Compatible with `no_std` environments.
+#[cfg(feature = "alloc")]
+mod cache;
+mod detection;
+mod iter;
+mod ordered;
+mod quantizer;
+mod table;
 #[cfg(test)]
 mod tests;
 
 pub use crate::error::PitchyError;
+#[cfg(feature = "alloc")]
+pub use cache::PitchCache;
+pub use detection::NoteDetection;
+pub use iter::{PitchIterExt, Transposed};
+pub use ordered::OrderedPitch;
+pub use quantizer::PitchQuantizer;
+pub use table::MIDI_FREQUENCIES;
 
+use core::ops::{Add, RangeInclusive, Sub};
 use core::str::FromStr;
 
-use crate::{Note, math::*};
+use crate::{
+    Direction, EqualTemperament, Interval, Note, SpellingPreference, Temperament,
+    math::*,
+    note::{parse_helmholtz, parse_note, parse_pitch_class},
+};
+
+/// How a fractional MIDI note number is rounded to a whole note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest note (ties away from zero).
+    Nearest,
+    /// Round down to the note at or below the pitch.
+    Floor,
+    /// Round up to the note at or above the pitch.
+    Ceil,
+}
 
 /// A musical pitch represented purely by its frequency in Hertz (Hz).
 ///
 /// This type models raw sound frequency without symbolic context
 /// (e.g. note letters or accidentals). For notation-aware handling, see [`Note`].
+///
+/// # NaN handling
+///
+/// `PartialEq` compares the raw frequencies, so a pitch with a NaN frequency is not
+/// equal to anything, including itself. Use [`Pitch::is_finite`] to reject such pitches,
+/// and [`Pitch::total_cmp`], [`Pitch::min`] and [`Pitch::max`] (or [`OrderedPitch`])
+/// for comparisons that order NaN deterministically as the greatest value.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Pitch {
     /// The raw frequency in Hertz (Hz).
@@ -24,32 +61,405 @@ pub struct Pitch {
 }
 
 impl Pitch {
+    /// Frequency of MIDI note 0 (C-1), the lowest note in the MIDI range, in Hz.
+    pub const MIN_MIDI_FREQUENCY: f64 = MIDI_FREQUENCIES[0];
+
+    /// Frequency of MIDI note 127 (G9), the highest note in the MIDI range, in Hz.
+    pub const MAX_MIDI_FREQUENCY: f64 = MIDI_FREQUENCIES[127];
+
+    /// Returns the range of valid MIDI note numbers, `0..=127`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert!(Pitch::midi_range().contains(&69));
+    /// assert!(!Pitch::midi_range().contains(&128));
+    /// ```
+    #[must_use]
+    pub const fn midi_range() -> RangeInclusive<u8> {
+        0..=127
+    }
+
     /// Creates a new pitch from the given frequency in Hertz (Hz).
     ///
     /// # Arguments
     ///
     /// * `frequency` – The frequency in Hz representing the pitch.
+    #[must_use]
     pub fn new(frequency: f64) -> Self {
         Self { frequency }
     }
 
+    /// Parses a pitch from any supported notation, for forgiving user input.
+    ///
+    /// The input style is detected in this order:
+    ///
+    /// 1. Starts with a digit or `.`: a frequency in Hz, optionally followed by a
+    ///    case-insensitive `Hz` or `kHz` unit (`"440"`, `"440 Hz"`, `"1.2kHz"`).
+    /// 2. Ends with a digit: scientific pitch notation, as accepted by [`FromStr`] (`"C4"`, `"Db-1"`).
+    /// 3. Anything else: Helmholtz notation (`"c'"`, `"C,"`, `"f#''"`).
+    ///
+    /// A bare letter is therefore read as Helmholtz, so `"C"` is C2 and `"c"` is C3, and `"bb'"`
+    /// is B♭4. Frequencies must be finite and positive but are not checked against the MIDI
+    /// range; note names are, as with [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    /// use std::str::FromStr;
+    ///
+    /// let c4 = Pitch::from_str("C4").unwrap();
+    /// assert_eq!(Pitch::from_str_any("c'").unwrap(), c4);
+    /// assert_eq!(Pitch::from_str_any("1.2kHz").unwrap(), Pitch::new(1200.0));
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn from_str_any(s: &str) -> Result<Self, PitchyError> {
+        let s = s.trim();
+
+        if s.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            parse_frequency(s).map(Self::new)
+        } else if s.ends_with(|c: char| c.is_ascii_digit()) {
+            Self::from_str(s)
+        } else {
+            Self::try_from(parse_helmholtz(s)?)
+        }
+    }
+
+    /// Parses an octave-less note name (e.g., `"C#"`, `"Bb"`) into its pitch class,
+    /// 0–11 with C = 0.
+    ///
+    /// Accepts the same letters and accidentals as [`FromStr`], which still requires an
+    /// octave. Spellings that cross the octave boundary wrap, so `"Cb"` is 11 and `"B#"` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::pitch_class_from_str("C#").unwrap(), 1);
+    /// assert_eq!(Pitch::pitch_class_from_str("Bb").unwrap(), 10);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn pitch_class_from_str(s: &str) -> Result<u8, PitchyError> {
+        let (letter, accidental) = parse_pitch_class(s)?;
+
+        Ok((letter as i8 + accidental as i8).rem_euclid(12) as u8)
+    }
+
     /// Creates a pitch from a MIDI note number in the range 0–127.
     ///
+    /// The frequency is read from [`MIDI_FREQUENCIES`] (A4 = 440 Hz) rather than computed.
     /// Returns an error if the MIDI number is out of range.
+    #[must_use = "this returns a Result that may hold an error"]
     pub fn try_from_midi_number(midi: u8) -> Result<Self, PitchyError> {
+        let frequency = *MIDI_FREQUENCIES
+            .get(midi as usize)
+            .ok_or(PitchyError::OutOfMidiRange(midi as i16))?;
+
+        Ok(Self { frequency })
+    }
+
+    /// Converts a slice of MIDI note numbers into pitches.
+    ///
+    /// Fails on the first out-of-range value, returning its index alongside the error.
+    /// Only available when the `std` feature is enabled; see [`Pitch::from_midi_slice_into`]
+    /// for a `no_std` variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Pitch, PitchyError};
+    ///
+    /// let pitches = Pitch::from_midi_slice(&[60, 64, 67]).unwrap();
+    /// assert_eq!(pitches.len(), 3);
+    ///
+    /// let err = Pitch::from_midi_slice(&[60, 200]).unwrap_err();
+    /// assert_eq!(err, (1, PitchyError::OutOfMidiRange(200)));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn from_midi_slice(midis: &[u8]) -> Result<Vec<Self>, (usize, PitchyError)> {
+        midis
+            .iter()
+            .enumerate()
+            .map(|(index, &midi)| Self::try_from_midi_number(midi).map_err(|err| (index, err)))
+            .collect()
+    }
+
+    /// Converts MIDI note numbers into pitches, writing them into `out`.
+    ///
+    /// Converts `min(midis.len(), out.len())` values and returns how many were written.
+    /// Fails on the first out-of-range value, returning its index alongside the error;
+    /// pitches before that index have already been written.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn from_midi_slice_into(
+        midis: &[u8],
+        out: &mut [Pitch],
+    ) -> Result<usize, (usize, PitchyError)> {
+        for (index, (&midi, slot)) in midis.iter().zip(out.iter_mut()).enumerate() {
+            *slot = Self::try_from_midi_number(midi).map_err(|err| (index, err))?;
+        }
+
+        Ok(midis.len().min(out.len()))
+    }
+
+    /// Returns every in-range MIDI pitch with the given pitch class, lowest first.
+    ///
+    /// `pitch_class` is taken modulo 12 (C = 0), so C yields MIDI 0, 12, …, 120.
+    /// Only available when the `std` feature is enabled; see [`Pitch::octaves_of`]
+    /// for a `no_std` iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let cs = Pitch::all_octaves_of(0);
+    /// assert_eq!(cs.len(), 11);
+    /// assert_eq!(cs[5].try_midi_number().unwrap(), 60);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn all_octaves_of(pitch_class: u8) -> Vec<Self> {
+        Self::octaves_of(pitch_class).collect()
+    }
+
+    /// Lazily yields every in-range MIDI pitch with the given pitch class, lowest first.
+    ///
+    /// `pitch_class` is taken modulo 12 (C = 0). See [`Pitch::all_octaves_of`].
+    pub fn octaves_of(pitch_class: u8) -> impl Iterator<Item = Self> {
+        MIDI_FREQUENCIES[(pitch_class % 12) as usize..]
+            .iter()
+            .step_by(12)
+            .map(|&frequency| Self { frequency })
+    }
+
+    /// Creates a pitch from a MIDI note number using the given [`Temperament`].
+    ///
+    /// Returns an error if the MIDI number is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{EqualTemperament, Pitch};
+    ///
+    /// let a4 = Pitch::from_midi_in(69, &EqualTemperament { a4_hz: 442.0 }).unwrap();
+    /// assert_eq!(a4.frequency(), 442.0);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn from_midi_in(midi: u8, temperament: &impl Temperament) -> Result<Self, PitchyError> {
         if midi > 127 {
-            return Err(PitchyError::OutOfMidiRange(midi));
+            return Err(PitchyError::OutOfMidiRange(midi as i16));
         }
-        let frequency = powf2((midi as f64 - 69.0) / 12.0) * 440.0;
 
-        Ok(Self { frequency })
+        Ok(Self {
+            frequency: temperament.frequency(midi),
+        })
+    }
+
+    /// Creates a pitch at the center frequency of an FFT bin: `bin * sample_rate / fft_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let pitch = Pitch::from_fft_bin(41, 4096, 44100.0);
+    /// assert!((pitch.frequency() - 441.43).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn from_fft_bin(bin: usize, fft_size: usize, sample_rate: f64) -> Self {
+        Self {
+            frequency: bin as f64 * sample_rate / fft_size as f64,
+        }
+    }
+
+    /// Returns the index of the FFT bin whose center frequency is nearest to this pitch.
+    ///
+    /// The inverse of [`Pitch::from_fft_bin`]. The index is not limited to the Nyquist bin
+    /// (`fft_size / 2`); negative or NaN frequencies map to bin 0.
+    #[must_use]
+    pub fn nearest_fft_bin(&self, fft_size: usize, sample_rate: f64) -> usize {
+        // Float-to-int casts saturate, so out-of-domain values clamp instead of wrapping
+        round(self.frequency * fft_size as f64 / sample_rate) as usize
     }
 
     /// Returns the frequency of this pitch in Hertz (Hz).
+    #[must_use]
     pub fn frequency(&self) -> f64 {
         self.frequency
     }
 
+    /// Returns the frequency of this pitch in kilohertz (kHz).
+    #[must_use]
+    pub fn frequency_khz(&self) -> f64 {
+        self.frequency / 1000.0
+    }
+
+    /// Returns the frequency of this pitch in millihertz (mHz).
+    #[must_use]
+    pub fn frequency_millihz(&self) -> f64 {
+        self.frequency * 1000.0
+    }
+
+    /// Formats the frequency in Hz with exactly `decimals` digits after the decimal point,
+    /// without a unit.
+    /// Only available when the `std` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(440.0).format_frequency(1), "440.0");
+    /// assert_eq!(Pitch::new(261.6256).format_frequency(2), "261.63");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn format_frequency(&self, decimals: usize) -> String {
+        format!("{:.*}", decimals, self.frequency)
+    }
+
+    /// Returns the continuous MIDI note number of this pitch, `69 + 12 * log2(frequency / 440)`.
+    ///
+    /// Unlike [`Pitch::try_midi_number`], the value is neither rounded nor range-checked,
+    /// which suits pitch-bend calculations and smooth displays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(440.0).midi_number_f64(), 69.0);
+    /// ```
+    #[must_use]
+    pub fn midi_number_f64(&self) -> f64 {
+        self.fractional_midi()
+    }
+
+    /// Returns the distance of this pitch above MIDI note 0 (C-1) in cents.
+    ///
+    /// For example, A4 (MIDI 69) is 6900 cents above MIDI 0. Pitches below MIDI 0 are negative.
+    #[must_use]
+    pub fn cents_from_c0(&self) -> f64 {
+        self.fractional_midi() * 100.0
+    }
+
+    /// Returns `true` if the frequency is neither infinite nor NaN.
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.frequency.is_finite()
+    }
+
+    /// Returns a total ordering between two pitches by frequency.
+    ///
+    /// NaN frequencies compare equal to each other and greater than every other value,
+    /// so sorting with this comparator never panics and groups bad pitches at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let mut pitches = [Pitch::new(f64::NAN), Pitch::new(880.0), Pitch::new(440.0)];
+    /// pitches.sort_by(Pitch::total_cmp);
+    /// assert_eq!(pitches[0].frequency(), 440.0);
+    /// assert!(pitches[2].frequency().is_nan());
+    /// ```
+    #[must_use]
+    pub fn total_cmp(&self, other: &Pitch) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+
+        match (self.frequency.is_nan(), other.frequency.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.frequency.total_cmp(&other.frequency),
+        }
+    }
+
+    /// Returns the lower of two pitches according to [`Pitch::total_cmp`].
+    ///
+    /// A NaN pitch is only returned if both pitches are NaN.
+    #[must_use]
+    pub fn min(self, other: Pitch) -> Pitch {
+        if other.total_cmp(&self).is_lt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the higher of two pitches according to [`Pitch::total_cmp`].
+    ///
+    /// NaN is treated as the greatest value, so it wins over any other pitch.
+    #[must_use]
+    pub fn max(self, other: Pitch) -> Pitch {
+        if other.total_cmp(&self).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the raw bit pattern of the frequency, as produced by [`f64::to_bits`].
+    ///
+    /// Useful for storing pitches in custom binary formats without extra dependencies.
+    /// The value is in host order; callers are responsible for choosing a byte order
+    /// (e.g., via [`u64::to_le_bytes`]) when writing it out.
+    #[must_use]
+    pub fn to_bits(&self) -> u64 {
+        self.frequency.to_bits()
+    }
+
+    /// Restores a pitch from a bit pattern produced by [`Pitch::to_bits`].
+    #[must_use]
+    pub fn from_bits(bits: u64) -> Self {
+        Self {
+            frequency: f64::from_bits(bits),
+        }
+    }
+
+    /// Packs this pitch into 16 bits as a MIDI number plus a whole-cent offset.
+    ///
+    /// The layout is `MMMMMMMC CCCCCCCC`: the high 7 bits hold the nearest MIDI note
+    /// (0–127) and the low 9 bits a two's-complement cent offset (−256 to +255) from it.
+    /// The offset is rounded to the nearest cent, so the packed value has 1-cent precision.
+    ///
+    /// Returns `None` if the pitch is outside the MIDI range or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(440.0).to_packed_u16(), Some(69 << 9));
+    /// ```
+    #[must_use]
+    pub fn to_packed_u16(&self) -> Option<u16> {
+        let (midi, cents) = self.midi_number_with_cents().ok()?;
+        let cents = round(cents) as i16;
+
+        Some((midi as u16) << 9 | (cents as u16 & 0x1FF))
+    }
+
+    /// Restores a pitch from a value produced by [`Pitch::to_packed_u16`].
+    ///
+    /// Every `u16` decodes to a pitch, including offsets beyond ±50 cents.
+    #[must_use]
+    pub fn from_packed_u16(packed: u16) -> Self {
+        let midi = (packed >> 9) as f64;
+        // Shift the 9-bit offset to the top of an i16 and back to sign-extend it
+        let cents = ((packed << 7) as i16 >> 7) as f64;
+
+        Self {
+            frequency: midi_frequency(midi + cents / 100.0),
+        }
+    }
+
     /// Transposes this pitch by a number of semitones.
     ///
     /// Positive values raise the pitch; negative values lower it.
@@ -65,87 +475,1090 @@ impl Pitch {
     /// let expected_hz = 293.665;
     /// assert!((transposed.frequency() - expected_hz).abs() < 0.01);
     /// ```
+    ///
+    /// Pitches are immutable, so the transposed pitch must be used:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use pitchy::Pitch;
+    ///
+    /// let pitch = Pitch::new(440.0);
+    /// pitch.transpose(12.0); // error: unused return value of `Pitch::transpose`
+    /// ```
+    #[must_use]
     pub fn transpose(&self, semitones: f64) -> Self {
+        self.transpose_with_ratio(semitones).0
+    }
+
+    /// Transposes this pitch and snaps the result to the nearest equal-tempered note.
+    ///
+    /// Keeps slightly detuned input from drifting further off the grid after transposition.
+    /// The result is snapped even outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// // A slightly sharp A4 lands exactly on B4
+    /// let b4 = Pitch::new(443.0).transpose_snapped(2.0);
+    /// assert_eq!(b4, Pitch::try_from_midi_number(71).unwrap());
+    /// ```
+    #[must_use]
+    pub fn transpose_snapped(&self, semitones: f64) -> Self {
+        let transposed = self.transpose(semitones);
+
         Self {
-            frequency: self.frequency * powf2(semitones / 12.0),
+            frequency: midi_frequency(round(transposed.fractional_midi())),
         }
     }
 
-    /// Approximates the MIDI note number corresponding to this frequency.
+    /// Snaps this pitch to the nearest multiple of `grid_cents` above MIDI note 0 (C-1).
     ///
-    /// Returns `Ok(midi)` if the frequency corresponds to a valid MIDI note (0–127),
-    /// otherwise returns `PitchyError::OutOfMidiRange(fallback)` where the fallback
-    /// is the nearest clamped `u8` approximation.
-    pub fn try_midi_number(&self) -> Result<u8, PitchyError> {
-        let midi = 69.0 + 12.0 * log2(self.frequency / 440.0);
-        let rounded = round(midi);
+    /// A 100-cent grid snaps to equal-tempered semitones and a 50-cent grid to quarter-tones.
+    /// The grid is anchored at A4 = 440 Hz like [`Pitch::cents_from_c0`]. A grid that is not
+    /// finite and positive leaves the pitch unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// // 450 Hz is about 39 cents above A4, closest to the quarter-tone above it
+    /// let quarter_tone = Pitch::new(450.0).quantize_to_cents_grid(50.0);
+    /// assert!((quarter_tone.frequency() - 452.89).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn quantize_to_cents_grid(&self, grid_cents: f64) -> Self {
+        if !grid_cents.is_finite() || grid_cents <= 0.0 {
+            return *self;
+        }
+        let cents = round(self.cents_from_c0() / grid_cents) * grid_cents;
 
-        if (0.0..=127.0).contains(&rounded) {
-            Ok(rounded as u8)
-        } else {
-            let fallback = rounded.clamp(0.0, 127.0) as u8;
-            Err(PitchyError::OutOfMidiRange(fallback))
+        Self {
+            frequency: midi_frequency(cents / 100.0),
         }
     }
 
-    /// Returns the octave number for this pitch, based on the MIDI standard.
+    /// Transposes this pitch and clamps the result into the MIDI range.
     ///
-    /// MIDI 69 (A4) maps to octave 4. MIDI 0 (C-1) maps to octave -1.
+    /// This is lossy near the edges: anything transposed past MIDI 0 or 127 lands exactly
+    /// on that note's frequency, so transposing back does not restore the original pitch.
+    /// See [`Pitch::clamped_to_midi_range`].
     ///
-    /// Returns `None` if the frequency is outside the MIDI range.
-    pub fn octave(&self) -> Option<i8> {
-        self.try_midi_number().ok().map(|midi| midi as i8 / 12 - 1)
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    /// use std::str::FromStr;
+    ///
+    /// let g9 = Pitch::from_str("G9").unwrap();
+    /// assert_eq!(g9.transpose_saturating(5.0), g9);
+    /// ```
+    #[must_use]
+    pub fn transpose_saturating(&self, semitones: f64) -> Self {
+        self.transpose(semitones).clamped_to_midi_range()
     }
-}
 
-/// Parses a pitch from a note name string (e.g., "C4", "A#3", "Db5").
-///
-/// Accepts sharps (`#`) or flats (`b`) and supports octaves from -1 to 9.
-///
-/// Returns an error if the format is invalid or the note is out of range.
-impl FromStr for Pitch {
-    type Err = PitchyError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        if s.len() < 2 || s.len() > 4 {
-            return Err(PitchyError::InvalidName);
-        }
-
-        let split_index = s
-            .find(|c: char| c.is_ascii_digit() || c == '-')
-            .ok_or(PitchyError::InvalidOctave)?;
-        let (note_part, octave_str) = s.split_at(split_index);
-        let octave: i8 = octave_str.parse().map_err(|_| PitchyError::InvalidOctave)?;
-
-        let semitone = match note_part {
-            n if n.eq_ignore_ascii_case("C") => 0,
-            n if n.eq_ignore_ascii_case("C#") || n.eq_ignore_ascii_case("Db") => 1,
-            n if n.eq_ignore_ascii_case("D") => 2,
-            n if n.eq_ignore_ascii_case("D#") || n.eq_ignore_ascii_case("Eb") => 3,
-            n if n.eq_ignore_ascii_case("E") => 4,
-            n if n.eq_ignore_ascii_case("F") => 5,
-            n if n.eq_ignore_ascii_case("F#") || n.eq_ignore_ascii_case("Gb") => 6,
-            n if n.eq_ignore_ascii_case("G") => 7,
-            n if n.eq_ignore_ascii_case("G#") || n.eq_ignore_ascii_case("Ab") => 8,
-            n if n.eq_ignore_ascii_case("A") => 9,
-            n if n.eq_ignore_ascii_case("A#") || n.eq_ignore_ascii_case("Bb") => 10,
-            n if n.eq_ignore_ascii_case("B") => 11,
-            _ => return Err(PitchyError::InvalidName),
-        };
+    /// Transposes this pitch, failing if the result is not within the MIDI range.
+    ///
+    /// The range check matches [`Pitch::try_midi_number`], so the error carries the
+    /// rounded MIDI number the transposition would have landed on. Unlike
+    /// [`Pitch::transpose_saturating`], this never silently moves a note.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Pitch, PitchyError};
+    /// use std::str::FromStr;
+    ///
+    /// let c_minus_1 = Pitch::from_str("C-1").unwrap();
+    /// assert_eq!(
+    ///     c_minus_1.transpose_checked(-1.0),
+    ///     Err(PitchyError::OutOfMidiRange(-1))
+    /// );
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn transpose_checked(&self, semitones: f64) -> Result<Self, PitchyError> {
+        let transposed = self.transpose(semitones);
+        transposed.try_midi_number()?;
 
-        let midi = (octave as i16)
-            .checked_add(1)
-            .and_then(|v| v.checked_mul(12))
-            .and_then(|v| v.checked_add(semitone as i16))
-            .ok_or(PitchyError::MidiOverflow)?;
+        Ok(transposed)
+    }
 
-        if !(0..=127).contains(&midi) {
-            return Err(PitchyError::OutOfMidiRange(midi as u8));
+    /// Transposes this pitch by a named [`Interval`] in the given [`Direction`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Direction, Interval, Pitch};
+    /// use std::str::FromStr;
+    ///
+    /// let c4 = Pitch::from_str("C4").unwrap();
+    /// let e4 = c4.transpose_by(Interval::MajorThird, Direction::Up);
+    /// assert_eq!(e4.try_midi_number().unwrap(), 64);
+    /// ```
+    #[must_use]
+    pub fn transpose_by(&self, interval: Interval, direction: Direction) -> Self {
+        let semitones = interval.semitones() as f64;
+        match direction {
+            Direction::Up => self.transpose(semitones),
+            Direction::Down => self.transpose(-semitones),
         }
+    }
+
+    /// Transposes this pitch like [`Pitch::transpose`], also returning the frequency
+    /// ratio `2^(semitones / 12)` that was applied.
+    ///
+    /// Multiplying the returned ratios lets callers chain many transpositions
+    /// without accumulating rounding error in the intermediate pitches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let (up, ratio) = Pitch::new(440.0).transpose_with_ratio(12.0);
+    /// assert_eq!(ratio, 2.0);
+    /// assert_eq!(up.frequency(), 880.0);
+    /// ```
+    #[must_use]
+    pub fn transpose_with_ratio(&self, semitones: f64) -> (Self, f64) {
+        let ratio = powf2(semitones / 12.0);
+        let pitch = Self {
+            frequency: self.frequency * ratio,
+        };
+
+        (pitch, ratio)
+    }
 
-        let hz = powf2((midi as f64 - 69.0) / 12.0) * 440.0;
-        Ok(Pitch::new(hz))
+    /// Re-anchors this pitch from the `from_a4` tuning reference to `to_a4`, keeping its note.
+    ///
+    /// The frequency is scaled by `to_a4 / from_a4`; dividing by `from_a4` first means
+    /// the reference itself maps exactly, so A4 at 440 Hz retunes to exactly 442 Hz.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(440.0).retune(440.0, 442.0).frequency(), 442.0);
+    /// ```
+    #[must_use]
+    pub fn retune(&self, from_a4: f64, to_a4: f64) -> Self {
+        Self {
+            frequency: self.frequency / from_a4 * to_a4,
+        }
+    }
+
+    /// Raises this pitch by exactly one octave (doubles the frequency).
+    #[must_use]
+    pub fn octave_up(&self) -> Self {
+        Self {
+            frequency: self.frequency * 2.0,
+        }
+    }
+
+    /// Lowers this pitch by exactly one octave (halves the frequency).
+    #[must_use]
+    pub fn octave_down(&self) -> Self {
+        Self {
+            frequency: self.frequency / 2.0,
+        }
+    }
+
+    /// Shifts this pitch by `octaves` whole octaves.
+    ///
+    /// Positive values raise the pitch; negative values lower it. Since the factor is
+    /// an exact power of two, this avoids the rounding error of `transpose(12.0 * n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(440.0).shift_octaves(-2).frequency(), 110.0);
+    /// ```
+    #[must_use]
+    pub fn shift_octaves(&self, octaves: i32) -> Self {
+        Self {
+            frequency: self.frequency * powf2(octaves as f64),
+        }
+    }
+
+    /// Transposes this pitch, then folds the result by whole octaves into `base_octave`.
+    ///
+    /// The octave is judged from the nearest MIDI note, as with [`Pitch::octave`], so any
+    /// detuning is preserved. Returns `PitchyError::InvalidFrequency` if the transposed
+    /// frequency is not finite and positive, and `PitchyError::OutOfMidiRange` if the folded
+    /// pitch falls outside the MIDI range (e.g., an A folded into octave 9).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    /// use std::str::FromStr;
+    ///
+    /// let b4 = Pitch::from_str("B4").unwrap();
+    /// let c_sharp4 = Pitch::from_str("C#4").unwrap();
+    /// assert!(b4.transpose_within_octave(2, 4).unwrap().approx_eq(&c_sharp4, 1e-9));
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn transpose_within_octave(
+        &self,
+        semitones: i32,
+        base_octave: i8,
+    ) -> Result<Self, PitchyError> {
+        let transposed = self.transpose(semitones as f64);
+        if !transposed.frequency.is_finite() || transposed.frequency <= 0.0 {
+            return Err(PitchyError::InvalidFrequency);
+        }
+        let octave = transposed.octave_from_frequency();
+        let folded = transposed.shift_octaves((base_octave as i32).saturating_sub(octave));
+
+        folded.try_midi_number()?;
+        Ok(folded)
+    }
+
+    /// Reverses a [`Pitch::transpose`] by the same number of semitones.
+    ///
+    /// Mathematically this is `transpose(-semitones)`, but it divides by the exact
+    /// factor used by `transpose` instead of multiplying by its reciprocal, which keeps
+    /// round-trips stable in floating point: `transpose(s).untranspose(s)` returns the
+    /// original frequency exactly in most cases and is never off by more than one ulp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let pitch = Pitch::new(440.0);
+    /// assert_eq!(pitch.transpose(7.0).untranspose(7.0), pitch);
+    /// ```
+    #[must_use]
+    pub fn untranspose(&self, semitones: f64) -> Self {
+        Self {
+            frequency: self.frequency / powf2(semitones / 12.0),
+        }
+    }
+
+    /// Returns `count` logarithmically spaced pitches starting at `start`,
+    /// with `bins_per_octave` pitches per octave.
+    ///
+    /// This yields the bin center frequencies used by constant-Q and chroma transforms.
+    /// Each pitch is computed directly from `start`, so errors do not accumulate.
+    /// Yields nothing if `bins_per_octave` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let bins: Vec<Pitch> = Pitch::bins_per_octave(Pitch::new(440.0), 24, 3).collect();
+    /// assert!((bins[2].frequency() - 466.16).abs() < 0.01); // A#4
+    /// ```
+    pub fn bins_per_octave(
+        start: Pitch,
+        bins_per_octave: u32,
+        count: usize,
+    ) -> impl Iterator<Item = Pitch> {
+        let count = if bins_per_octave == 0 { 0 } else { count };
+
+        (0..count).map(move |bin| Pitch {
+            frequency: start.frequency * powf2(bin as f64 / bins_per_octave as f64),
+        })
+    }
+
+    /// Fills `buffer` with frequencies interpolated logarithmically from `start` to `end`.
+    ///
+    /// The first and last samples are the endpoint frequencies, and consecutive samples
+    /// are an equal number of cents apart, giving a sweep that is linear in pitch.
+    /// A single-sample buffer receives `start`.
+    pub fn sweep_into(start: Pitch, end: Pitch, buffer: &mut [f64]) {
+        let count = buffer.len();
+
+        for (i, sample) in buffer.iter_mut().enumerate() {
+            *sample = log_interpolate(start.frequency, end.frequency, i, count);
+        }
+    }
+
+    /// Returns `samples` frequencies interpolated logarithmically from `start` to `end`.
+    ///
+    /// See [`Pitch::sweep_into`] for the `no_std` variant filling a caller-provided buffer.
+    /// Only available when the `std` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let sweep = Pitch::sweep(Pitch::new(220.0), Pitch::new(880.0), 3);
+    /// assert!((sweep[1] - 440.0).abs() < 1e-9);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn sweep(start: Pitch, end: Pitch, samples: usize) -> Vec<f64> {
+        let mut buffer = vec![0.0; samples];
+        Self::sweep_into(start, end, &mut buffer);
+        buffer
+    }
+
+    /// Lazily yields `steps` pitches gliding logarithmically from `start` to `end`.
+    ///
+    /// Like [`Pitch::sweep_into`], both endpoints are included and consecutive pitches
+    /// are an equal number of cents apart, matching how pitch is perceived. A single
+    /// step yields `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let mut gliss = Pitch::glissando(&Pitch::new(220.0), &Pitch::new(880.0), 3);
+    /// assert_eq!(gliss.next(), Some(Pitch::new(220.0)));
+    /// assert!((gliss.next().unwrap().frequency() - 440.0).abs() < 1e-9);
+    /// ```
+    pub fn glissando(
+        start: &Pitch,
+        end: &Pitch,
+        steps: usize,
+    ) -> impl Iterator<Item = Pitch> + use<> {
+        // The iterator copies both frequencies, so it does not borrow the endpoints
+        let (start, end) = (start.frequency, end.frequency);
+
+        (0..steps).map(move |step| Pitch {
+            frequency: log_interpolate(start, end, step, steps),
+        })
+    }
+
+    /// Returns the frequency ratio from this pitch to `other` (`other / self`).
+    ///
+    /// For example, an octave up is `2.0` and a just perfect fifth is `1.5`.
+    /// Returns `None` if this pitch has a frequency of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let a4 = Pitch::new(440.0);
+    /// assert_eq!(a4.ratio_to(&Pitch::new(880.0)), Some(2.0));
+    /// assert_eq!(Pitch::new(0.0).ratio_to(&a4), None);
+    /// ```
+    #[must_use]
+    pub fn ratio_to(&self, other: &Pitch) -> Option<f64> {
+        if self.frequency == 0.0 {
+            return None;
+        }
+
+        Some(other.frequency / self.frequency)
+    }
+
+    /// Expresses this pitch relative to `reference` as a frequency ratio and a distance in cents.
+    ///
+    /// The ratio is `self / reference`, so pitches above the reference have a ratio above 1
+    /// and positive cents. Returns `None` if the reference has a frequency of zero; see
+    /// [`Pitch::ratio_to`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let scientific_c = Pitch::new(256.0);
+    /// let (ratio, cents) = Pitch::new(384.0).relative_to(&scientific_c).unwrap();
+    /// assert_eq!(ratio, 1.5);
+    /// assert!((cents - 701.96).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn relative_to(&self, reference: &Pitch) -> Option<(f64, f64)> {
+        let ratio = reference.ratio_to(self)?;
+
+        Some((ratio, 1200.0 * log2(ratio)))
+    }
+
+    /// Returns `true` if the frequencies of the two pitches differ by at most `tolerance_hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert!(Pitch::new(440.0).approx_eq(&Pitch::new(440.005), 0.01));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: &Pitch, tolerance_hz: f64) -> bool {
+        (self.frequency - other.frequency).abs() <= tolerance_hz
+    }
+
+    /// Returns `true` if the two pitches are at most `tolerance_cents` apart.
+    ///
+    /// Unlike [`Pitch::approx_eq`], the tolerance scales with register,
+    /// which matches how pitch differences are perceived.
+    #[must_use]
+    pub fn approx_eq_cents(&self, other: &Pitch, tolerance_cents: f64) -> bool {
+        self.cents_to(other).abs() <= tolerance_cents
+    }
+
+    /// Returns this pitch with its frequency clamped to
+    /// \[[`Pitch::MIN_MIDI_FREQUENCY`], [`Pitch::MAX_MIDI_FREQUENCY`]\].
+    ///
+    /// Frequencies between those of MIDI 0 and MIDI 127 are returned unchanged; anything
+    /// outside lands exactly on the edge note. This is stricter than [`Pitch::try_midi_number`],
+    /// which rounds: 8 Hz already counts as MIDI 0 there, yet is clamped up to C-1 here.
+    /// This is lossy: the original frequency of clamped pitches is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let clamped = Pitch::new(5.0).clamped_to_midi_range();
+    /// assert_eq!(clamped.try_midi_number().unwrap(), 0);
+    /// ```
+    #[must_use]
+    pub fn clamped_to_midi_range(&self) -> Self {
+        Self {
+            frequency: self
+                .frequency
+                .clamp(Self::MIN_MIDI_FREQUENCY, Self::MAX_MIDI_FREQUENCY),
+        }
+    }
+
+    /// Approximates the MIDI note number corresponding to this frequency.
+    ///
+    /// Returns `Ok(midi)` if the frequency corresponds to a valid MIDI note (0–127),
+    /// otherwise returns `PitchyError::OutOfMidiRange(midi)` carrying the rounded MIDI number
+    /// (saturated to the `i16` range), so callers can tell whether the pitch was too low or too high.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn try_midi_number(&self) -> Result<u8, PitchyError> {
+        self.midi_number_with_cents().map(|(midi, _)| midi)
+    }
+
+    /// Converts this frequency to a MIDI note number using the given [`RoundingMode`].
+    ///
+    /// `RoundingMode::Nearest` matches [`Pitch::try_midi_number`]; `Floor` and `Ceil`
+    /// are useful for bucketing frequencies deterministically.
+    ///
+    /// Returns `PitchyError::OutOfMidiRange(midi)` if the rounded number is outside 0–127.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Pitch, RoundingMode};
+    ///
+    /// let pitch = Pitch::new(450.0); // between A4 and A#4
+    /// assert_eq!(pitch.midi_number_rounded(RoundingMode::Floor).unwrap(), 69);
+    /// assert_eq!(pitch.midi_number_rounded(RoundingMode::Ceil).unwrap(), 70);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn midi_number_rounded(&self, mode: RoundingMode) -> Result<u8, PitchyError> {
+        let midi = self.fractional_midi();
+        // Absorb float noise so exact equal-tempered pitches floor and ceil to themselves
+        let nearest = round(midi);
+        let midi = if (midi - nearest).abs() < 1e-9 {
+            nearest
+        } else {
+            midi
+        };
+        let rounded = match mode {
+            RoundingMode::Nearest => round(midi),
+            RoundingMode::Floor => floor(midi),
+            RoundingMode::Ceil => ceil(midi),
+        };
+
+        if (0.0..=127.0).contains(&rounded) {
+            Ok(rounded as u8)
+        } else {
+            Err(PitchyError::OutOfMidiRange(rounded as i16))
+        }
+    }
+
+    /// Returns the nearest MIDI note number together with the signed deviation
+    /// from it in cents.
+    ///
+    /// The deviation lies within ±50 cents; positive values mean this pitch is sharp
+    /// of the returned note, negative values mean it is flat.
+    ///
+    /// The note is rounded exactly as in [`Pitch::try_midi_number`], and the deviation is
+    /// measured from that same note, so the two always agree. An exact quarter-tone midpoint
+    /// rounds up, giving the upper note at −50 cents; the deviation is thus in \[−50, +50).
+    ///
+    /// Returns `PitchyError::OutOfMidiRange(midi)` under the same conditions
+    /// as [`Pitch::try_midi_number`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let (midi, cents) = Pitch::new(450.0).midi_number_with_cents().unwrap();
+    /// assert_eq!(midi, 69); // A4
+    /// assert!((cents - 38.9).abs() < 0.1);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn midi_number_with_cents(&self) -> Result<(u8, f64), PitchyError> {
+        let midi = self.fractional_midi();
+        let rounded = round(midi);
+
+        if (0.0..=127.0).contains(&rounded) {
+            Ok((rounded as u8, (midi - rounded) * 100.0))
+        } else {
+            // Float-to-int casts saturate, so extreme frequencies stay well-signed
+            Err(PitchyError::OutOfMidiRange(rounded as i16))
+        }
+    }
+
+    /// Returns `true` if this pitch is within `tolerance_cents` of the nearest
+    /// equal-tempered note.
+    ///
+    /// Always returns `false` for pitches outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert!(Pitch::new(441.0).is_in_tune(10.0));
+    /// assert!(!Pitch::new(441.0).is_in_tune(2.0));
+    /// ```
+    #[must_use]
+    pub fn is_in_tune(&self, tolerance_cents: f64) -> bool {
+        self.midi_number_with_cents()
+            .is_ok_and(|(_, cents)| cents.abs() <= tolerance_cents)
+    }
+
+    /// Returns the nearest MIDI note with its deviation in cents and a confidence score.
+    ///
+    /// The note and cents match [`Pitch::midi_number_with_cents`]; the confidence is
+    /// `1.0 - cents.abs() / 50.0`, so 1.0 means exactly in tune. Returns `None` for
+    /// pitches outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let detection = Pitch::new(440.0).detect_note().unwrap();
+    /// assert_eq!(detection.note, 69);
+    /// assert_eq!(detection.confidence, 1.0);
+    /// ```
+    #[must_use]
+    pub fn detect_note(&self) -> Option<NoteDetection> {
+        let (note, cents) = self.midi_number_with_cents().ok()?;
+
+        Some(NoteDetection {
+            note,
+            cents,
+            confidence: 1.0 - cents.abs() / 50.0,
+        })
+    }
+
+    /// Returns whether the nearest equal-tempered note is a white key on a piano
+    /// (pitch class C, D, E, F, G, A or B).
+    ///
+    /// Unlike [`Note::is_natural`], this ignores spelling: a pitch parsed from `"E#4"`
+    /// sounds as F4 and is natural here. Returns `None` outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Pitch::from_str("C4").unwrap().is_natural(), Some(true));
+    /// assert_eq!(Pitch::from_str("C#4").unwrap().is_natural(), Some(false));
+    /// ```
+    #[must_use]
+    pub fn is_natural(&self) -> Option<bool> {
+        let midi = self.try_midi_number().ok()?;
+
+        Some(matches!(midi % 12, 0 | 2 | 4 | 5 | 7 | 9 | 11))
+    }
+
+    /// Formats this pitch as a fixed-width table row: nearest note name, MIDI number,
+    /// frequency in Hz and deviation from equal temperament in cents.
+    ///
+    /// Pitches outside the MIDI range show `-` in the note, MIDI and cents columns.
+    /// Only available when the `std` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(445.0).table_row(), "A4       69      445.00   +19.56");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn table_row(&self) -> String {
+        match self.midi_number_with_cents() {
+            Ok((midi, cents)) => {
+                let name = Note::try_from(*self)
+                    .map(|note| note.name())
+                    .unwrap_or_default();
+                format!(
+                    "{:<6}{:>5}{:>12.2}{:>+9.2}",
+                    name, midi, self.frequency, cents
+                )
+            }
+            Err(_) => format!("{:<6}{:>5}{:>12.2}{:>9}", "-", "-", self.frequency, "-"),
+        }
+    }
+
+    /// Spells the nearest equal-tempered note using the given accidental preference.
+    ///
+    /// A pitch stores only its frequency, so parsing `"Gb5"` and converting back with
+    /// `Note::try_from` yields `F#5`. Pass [`SpellingPreference::Flat`] to get `Gb5` instead,
+    /// or use [`Pitch::parse_as_note`] to keep the spelling that was typed.
+    ///
+    /// # Errors
+    /// Returns [`PitchyError::OutOfMidiRange`] if the pitch is outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, Pitch, SpellingPreference};
+    /// use std::str::FromStr;
+    ///
+    /// let g_flat5 = Pitch::from_str("Gb5").unwrap();
+    /// assert_eq!(
+    ///     g_flat5.into_note_preferring(SpellingPreference::Flat).unwrap(),
+    ///     Note::new(NoteLetter::G, Accidental::Flat, 5)
+    /// );
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn into_note_preferring(self, preference: SpellingPreference) -> Result<Note, PitchyError> {
+        Note::try_from_midi_number(self.try_midi_number()?, preference)
+    }
+
+    /// Parses a note name into a [`Note`], keeping the spelling exactly as written.
+    ///
+    /// Accepts the same names and applies the same range check as [`FromStr`]; see
+    /// [`crate::parse`] to get the pitch as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Accidental, Pitch};
+    ///
+    /// let note = Pitch::parse_as_note("Gb5").unwrap();
+    /// assert_eq!(note.accidental(), Accidental::Flat);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn parse_as_note(s: &str) -> Result<Note, PitchyError> {
+        crate::parse(s).map(|(note, _)| note)
+    }
+
+    /// Returns the name of the nearest equal-tempered note (e.g., `"A4"` for 445 Hz),
+    /// spelled with sharps, or `None` outside the MIDI range.
+    /// Only available when the `std` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(445.0).nearest_note_name().as_deref(), Some("A4"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn nearest_note_name(&self) -> Option<String> {
+        Note::try_from(*self).ok().map(|note| note.name())
+    }
+
+    /// Writes the name of the nearest equal-tempered note into any [`core::fmt::Write`] sink.
+    ///
+    /// This is the allocation-free counterpart of [`Pitch::nearest_note_name`]. Returns `None`,
+    /// writing nothing, if the pitch is outside the MIDI range, and otherwise the write result.
+    #[must_use]
+    pub fn write_nearest_note_name(
+        &self,
+        f: &mut impl core::fmt::Write,
+    ) -> Option<core::fmt::Result> {
+        Note::try_from(*self).ok().map(|note| note.write_name(f))
+    }
+
+    /// Returns the nearest equal-tempered note and the deviation from it in cents,
+    /// using `a4_hz` as the tuning reference instead of 440 Hz.
+    ///
+    /// The note uses the default sharp-based spelling. Returns `None` if the nearest
+    /// note falls outside the MIDI range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{NoteLetter, Pitch};
+    ///
+    /// // A 442 Hz ensemble reads 442 Hz as a perfectly tuned A4
+    /// let (note, cents) = Pitch::new(442.0).nearest_note_with_ref(442.0).unwrap();
+    /// assert_eq!(note.letter(), NoteLetter::A);
+    /// assert!(cents.abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn nearest_note_with_ref(&self, a4_hz: f64) -> Option<(Note, f64)> {
+        let midi = EqualTemperament { a4_hz }.midi_from_frequency(self.frequency);
+        let rounded = round(midi);
+        if !(0.0..=127.0).contains(&rounded) {
+            return None;
+        }
+
+        let pitch = Pitch::try_from_midi_number(rounded as u8).ok()?;
+        let note = Note::try_from(pitch).ok()?;
+        Some((note, (midi - rounded) * 100.0))
+    }
+
+    /// Returns the note from `available` closest to this pitch, together with the
+    /// deviation of this pitch from it in cents.
+    ///
+    /// Useful for instruments with a fixed set of playable notes. Notes outside the
+    /// MIDI range are ignored; returns `None` if no candidate remains.
+    /// Ties go to the note listed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Accidental, Note, NoteLetter, Pitch};
+    ///
+    /// let available = [
+    ///     Note::new(NoteLetter::F, Accidental::Natural, 4),
+    ///     Note::new(NoteLetter::B, Accidental::Natural, 4),
+    /// ];
+    ///
+    /// // A4 is a whole tone below B4 but a major third above F4
+    /// let (note, cents) = Pitch::new(440.0).nearest_available(&available).unwrap();
+    /// assert_eq!(note, available[1]);
+    /// assert!((cents + 200.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn nearest_available(&self, available: &[Note]) -> Option<(Note, f64)> {
+        available
+            .iter()
+            .filter_map(|&note| {
+                let pitch = Pitch::try_from(note).ok()?;
+                Some((note, pitch.cents_to(self)))
+            })
+            .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+    }
+
+    /// Returns the index of the pitch in `sorted` closest to this pitch, measured in cents.
+    ///
+    /// `sorted` must be in ascending order as by [`Pitch::total_cmp`]; it is binary-searched,
+    /// so lookups take O(log n). A pitch exactly halfway in cents between two neighbours picks
+    /// the higher one, as [`Pitch::try_midi_number`] does. Returns `None` if `sorted` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// let samples = [Pitch::new(220.0), Pitch::new(440.0), Pitch::new(880.0)];
+    /// assert_eq!(Pitch::new(600.0).nearest_in(&samples), Some(1));
+    /// assert_eq!(Pitch::new(700.0).nearest_in(&samples), Some(2));
+    /// ```
+    #[must_use]
+    pub fn nearest_in(&self, sorted: &[Pitch]) -> Option<usize> {
+        let upper = sorted.partition_point(|pitch| pitch.total_cmp(self).is_lt());
+
+        match (upper.checked_sub(1), sorted.get(upper)) {
+            (Some(lower), Some(above)) => {
+                let below = self.cents_to(&sorted[lower]).abs();
+                Some(if self.cents_to(above).abs() <= below {
+                    upper
+                } else {
+                    lower
+                })
+            }
+            (Some(lower), None) => Some(lower),
+            (None, Some(_)) => Some(upper),
+            (None, None) => None,
+        }
+    }
+
+    /// Snaps this pitch to the nearest equal-tempered note that belongs to a scale.
+    ///
+    /// `scale` lists the allowed pitch classes relative to `root_pitch_class`
+    /// (e.g., `[0, 2, 4, 5, 7, 9, 11]` for a major scale); values are taken modulo 12.
+    /// The in-scale note closest in cents wins, and exact ties round up.
+    ///
+    /// # Errors
+    /// Returns [`PitchyError::OutOfMidiRange`] if this pitch is outside the MIDI range,
+    /// or [`PitchyError::InvalidScale`] if `scale` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// const MAJOR: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+    ///
+    /// // 470 Hz lies between A#4 and B4; A#4 is not in C major, so it snaps to B4
+    /// let snapped = Pitch::new(470.0).snap_to_scale(&MAJOR, 0).unwrap();
+    /// assert_eq!(snapped.try_midi_number().unwrap(), 71);
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn snap_to_scale(&self, scale: &[u8], root_pitch_class: u8) -> Result<Self, PitchyError> {
+        self.try_midi_number()?;
+        let midi = self.fractional_midi();
+        let nearest = round(midi) as i16;
+
+        let in_scale = |candidate: i16| {
+            let pitch_class = (candidate - root_pitch_class as i16).rem_euclid(12);
+            scale.iter().any(|&pc| (pc % 12) as i16 == pitch_class)
+        };
+
+        // Any non-empty scale has a member within an octave of the nearest note
+        let snapped = (nearest - 12..=nearest + 12)
+            .filter(|candidate| (0..=127).contains(candidate) && in_scale(*candidate))
+            .min_by(|a, b| {
+                let distance_a = (*a as f64 - midi).abs();
+                let distance_b = (*b as f64 - midi).abs();
+                // Prefer the higher note on exact ties
+                distance_a.total_cmp(&distance_b).then(b.cmp(a))
+            })
+            .ok_or(PitchyError::InvalidScale)?;
+
+        Pitch::try_from_midi_number(snapped as u8)
+    }
+
+    /// Returns the octave number for this pitch, based on the MIDI standard.
+    ///
+    /// MIDI 69 (A4) maps to octave 4. MIDI 0 (C-1) maps to octave -1.
+    ///
+    /// Returns `None` if the frequency is outside the MIDI range;
+    /// use [`Pitch::octave_checked`] to tell too-low from too-high pitches.
+    #[must_use]
+    pub fn octave(&self) -> Option<i8> {
+        self.octave_checked().ok()
+    }
+
+    /// Returns the octave number for this pitch, like [`Pitch::octave`], or the reason
+    /// it has none.
+    ///
+    /// Out-of-range pitches yield `PitchyError::OutOfMidiRange(midi)` with the rounded
+    /// MIDI number, which is negative for subsonic pitches and above 127 for very high ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::{Pitch, PitchyError};
+    ///
+    /// assert_eq!(Pitch::new(440.0).octave_checked(), Ok(4));
+    /// assert!(matches!(
+    ///     Pitch::new(2.0).octave_checked(),
+    ///     Err(PitchyError::OutOfMidiRange(midi)) if midi < 0
+    /// ));
+    /// ```
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn octave_checked(&self) -> Result<i8, PitchyError> {
+        self.try_midi_number().map(midi_octave)
+    }
+
+    /// Returns the octave number of this pitch computed from the frequency alone,
+    /// so it is defined beyond the MIDI range (e.g., 16 kHz is octave 9).
+    ///
+    /// Octave boundaries match [`Pitch::octave`]: the pitch is rounded to its nearest
+    /// equal-tempered note first, so a slightly flat C5 is in octave 5. Within the MIDI
+    /// range both methods agree. The result is meaningless for non-positive or
+    /// non-finite frequencies, but never panics: 0 Hz saturates to `i32::MIN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    ///
+    /// assert_eq!(Pitch::new(16000.0).octave(), None);
+    /// assert_eq!(Pitch::new(16000.0).octave_from_frequency(), 9);
+    /// assert_eq!(Pitch::new(5.0).octave_from_frequency(), -2);
+    /// ```
+    #[must_use]
+    pub fn octave_from_frequency(&self) -> i32 {
+        // Subtract before the cast: it saturates, so 0 Hz gives i32::MIN instead of overflowing
+        (floor(round(self.fractional_midi()) / 12.0) - 1.0) as i32
+    }
+
+    /// Returns the position of this pitch within its octave as a value in `0.0..1.0`.
+    ///
+    /// Every C maps to `0.0`, and the value grows linearly in pitch towards the next C
+    /// (e.g., F# is `0.5`). This places pitch classes on a continuous circle, which is
+    /// handy for radial or chroma displays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::Pitch;
+    /// use std::str::FromStr;
+    ///
+    /// let a4 = Pitch::from_str("A4").unwrap();
+    /// assert!((a4.octave_position() - 0.75).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn octave_position(&self) -> f64 {
+        let octaves = self.fractional_midi() / 12.0;
+        octaves - floor(octaves)
+    }
+
+    /// Signed distance in cents from this pitch to `other`.
+    fn cents_to(&self, other: &Pitch) -> f64 {
+        1200.0 * log2(other.frequency / self.frequency)
+    }
+
+    /// The unrounded MIDI note number of this frequency, relative to A4 = 440 Hz.
+    fn fractional_midi(&self) -> f64 {
+        69.0 + 12.0 * log2(self.frequency / 440.0)
+    }
+}
+
+/// Transposes every pitch in place by the given number of semitones.
+///
+/// The ratio `2^(semitones / 12)` is computed once and applied to each frequency,
+/// so this matches calling [`Pitch::transpose`] on every element without the
+/// per-element exponentiation.
+///
+/// # Examples
+///
+/// ```
+/// use pitchy::Pitch;
+///
+/// let mut pitches = [Pitch::new(220.0), Pitch::new(440.0)];
+/// pitchy::transpose_all(&mut pitches, 12.0);
+/// assert_eq!(pitches, [Pitch::new(440.0), Pitch::new(880.0)]);
+/// ```
+pub fn transpose_all(pitches: &mut [Pitch], semitones: f64) {
+    let ratio = powf2(semitones / 12.0);
+    for pitch in pitches {
+        pitch.frequency *= ratio;
+    }
+}
+
+/// Formats pitches as an aligned table, one [`Pitch::table_row`] per line under a header.
+///
+/// The output is deterministic, which makes it suitable for snapshot tests.
+/// Only available when the `std` feature is enabled.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn pitch_table(pitches: &[Pitch]) -> String {
+    let mut table = format!("{:<6}{:>5}{:>12}{:>9}\n", "Note", "MIDI", "Hz", "Cents");
+    for pitch in pitches {
+        table.push_str(&pitch.table_row());
+        table.push('\n');
+    }
+    table
+}
+
+/// Prints [`pitch_table`] to standard output.
+/// Only available when the `std` feature is enabled.
+#[cfg(feature = "std")]
+pub fn print_pitch_table(pitches: &[Pitch]) {
+    print!("{}", pitch_table(pitches));
+}
+
+/// Defaults to A4 (440 Hz), the crate's reference pitch.
+impl Default for Pitch {
+    fn default() -> Self {
+        Self { frequency: 440.0 }
+    }
+}
+
+/// Transposes up by a number of **semitones**, not Hz: `pitch + 7` is a perfect fifth higher.
+///
+/// Equivalent to [`Pitch::transpose`].
+impl Add<i32> for Pitch {
+    type Output = Pitch;
+
+    fn add(self, semitones: i32) -> Pitch {
+        self.transpose(semitones as f64)
+    }
+}
+
+/// Transposes down by a number of **semitones**, not Hz: `pitch - 12` is an octave lower.
+///
+/// Equivalent to [`Pitch::transpose`] with a negated amount.
+impl Sub<i32> for Pitch {
+    type Output = Pitch;
+
+    fn sub(self, semitones: i32) -> Pitch {
+        self.transpose(-(semitones as f64))
+    }
+}
+
+/// Transposes up by a fractional number of **semitones**, not Hz: `pitch + 0.5` is a
+/// quarter tone higher.
+impl Add<f64> for Pitch {
+    type Output = Pitch;
+
+    fn add(self, semitones: f64) -> Pitch {
+        self.transpose(semitones)
+    }
+}
+
+/// Transposes down by a fractional number of **semitones**, not Hz.
+impl Sub<f64> for Pitch {
+    type Output = Pitch;
+
+    fn sub(self, semitones: f64) -> Pitch {
+        self.transpose(-semitones)
+    }
+}
+
+/// Octave of a MIDI note number in scientific pitch notation (MIDI 60 = C4).
+///
+/// Shared by [`Pitch`] and [`Note`] so both always agree on octave boundaries.
+pub(crate) fn midi_octave(midi: u8) -> i8 {
+    (midi / 12) as i8 - 1
+}
+
+/// Parses a positive frequency with an optional case-insensitive `Hz` or `kHz` suffix.
+fn parse_frequency(s: &str) -> Result<f64, PitchyError> {
+    let unit_start = |suffix: &str| {
+        s.len()
+            .checked_sub(suffix.len())
+            .filter(|&start| s.is_char_boundary(start) && s[start..].eq_ignore_ascii_case(suffix))
+    };
+
+    let (number, scale) = match (unit_start("khz"), unit_start("hz")) {
+        (Some(start), _) => (&s[..start], 1000.0),
+        (None, Some(start)) => (&s[..start], 1.0),
+        (None, None) => (s, 1.0),
+    };
+
+    let value: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| PitchyError::InvalidName)?;
+    let frequency = value * scale;
+    if !frequency.is_finite() || frequency <= 0.0 {
+        return Err(PitchyError::InvalidName);
+    }
+
+    Ok(frequency)
+}
+
+/// Frequency `index` of `count` points spaced equally in cents from `start` to `end` (Hz).
+///
+/// The first point is `start` and, for `count > 1`, the last is `end`.
+fn log_interpolate(start: f64, end: f64, index: usize, count: usize) -> f64 {
+    let steps = count.saturating_sub(1).max(1) as f64;
+
+    start * powf2(log2(end / start) * index as f64 / steps)
+}
+
+/// Equal-tempered frequency of a (possibly fractional) MIDI note number, relative to A4 = 440 Hz.
+fn midi_frequency(midi: f64) -> f64 {
+    powf2((midi - 69.0) / 12.0) * 440.0
+}
+
+/// Parses a pitch from a note name string (e.g., "C4", "A#3", "Db5", "F𝄪2").
+///
+/// Accepts ASCII accidentals (`#`, `b`, `##`, `x`, `bb`) as well as the Unicode glyphs
+/// `♯ ♭ 𝄪 𝄫`, and supports octaves from -1 to 9.
+///
+/// Returns an error if the format is invalid or the note is out of range.
+impl FromStr for Pitch {
+    type Err = PitchyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pitch::try_from(parse_note(s)?)
     }
 }
 
@@ -154,11 +1567,10 @@ impl TryFrom<Note> for Pitch {
     type Error = PitchyError;
 
     fn try_from(note: Note) -> Result<Pitch, PitchyError> {
-        let semitone = (note.letter() as i8) + (note.accidental() as i8);
-        let midi = ((note.octave() + 1) * 12 + semitone) as i16;
+        let midi = note.chromatic_index();
 
         if !(0..=127).contains(&midi) {
-            return Err(PitchyError::OutOfMidiRange(midi as u8));
+            return Err(PitchyError::OutOfMidiRange(midi as i16));
         }
 
         Pitch::try_from_midi_number(midi as u8)
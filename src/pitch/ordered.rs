@@ -0,0 +1,65 @@
+//! A totally ordered wrapper around [`Pitch`] for sorted collections.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::Pitch;
+
+/// A [`Pitch`] with a total order, suitable for `BTreeSet`, `BTreeMap` and sorting.
+///
+/// Pitches are compared with [`Pitch::total_cmp`], so every value has a well-defined
+/// position and all NaN frequencies sort last as a single equivalence class.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedPitch(Pitch);
+
+impl OrderedPitch {
+    /// Returns the wrapped pitch.
+    #[must_use]
+    pub fn pitch(&self) -> Pitch {
+        self.0
+    }
+}
+
+impl From<Pitch> for OrderedPitch {
+    fn from(pitch: Pitch) -> Self {
+        Self(pitch)
+    }
+}
+
+impl From<OrderedPitch> for Pitch {
+    fn from(ordered: OrderedPitch) -> Self {
+        ordered.0
+    }
+}
+
+impl PartialEq for OrderedPitch {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedPitch {}
+
+impl PartialOrd for OrderedPitch {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedPitch {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrderedPitch {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // All NaNs are equal under `total_cmp`, so they must share a hash
+        let bits = if self.0.frequency().is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            self.0.to_bits()
+        };
+        bits.hash(state);
+    }
+}
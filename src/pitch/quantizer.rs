@@ -0,0 +1,75 @@
+//! Stable nearest-note tracking for live pitch streams.
+
+use crate::Pitch;
+
+/// Snaps a stream of pitches to MIDI note numbers without flickering at note boundaries.
+///
+/// Plain nearest-note rounding toggles between neighbours when a detected pitch hovers
+/// around the quarter-tone midpoint. The quantizer keeps emitting the last note until
+/// the input moves more than `hysteresis_cents` past that midpoint.
+///
+/// # Examples
+///
+/// ```
+/// use pitchy::{Pitch, PitchQuantizer};
+///
+/// let mut quantizer = PitchQuantizer::new(10.0);
+/// let a4 = Pitch::new(440.0);
+///
+/// assert_eq!(quantizer.process(a4), Some(69));
+/// // 55 cents sharp would round to A#4, but stays within the hysteresis band
+/// assert_eq!(quantizer.process(a4.transpose(0.55)), Some(69));
+/// assert_eq!(quantizer.process(a4.transpose(0.65)), Some(70));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PitchQuantizer {
+    hysteresis_cents: f64,
+    last: Option<u8>,
+}
+
+impl PitchQuantizer {
+    /// Creates a quantizer that needs `hysteresis_cents` beyond the midpoint to switch notes.
+    ///
+    /// A threshold of `0.0` behaves like plain nearest-note rounding.
+    #[must_use]
+    pub fn new(hysteresis_cents: f64) -> Self {
+        Self {
+            hysteresis_cents,
+            last: None,
+        }
+    }
+
+    /// Returns the MIDI note number for the next pitch in the stream.
+    ///
+    /// Stays on the previously emitted note while the pitch is within
+    /// `50 + hysteresis_cents` cents of it, and otherwise switches to the nearest note.
+    /// Pitches outside the MIDI range yield `None` and clear the held note.
+    pub fn process(&mut self, pitch: Pitch) -> Option<u8> {
+        let Ok(nearest) = pitch.try_midi_number() else {
+            self.last = None;
+            return None;
+        };
+
+        let midi = pitch.cents_from_c0() / 100.0;
+        let note = match self.last {
+            Some(last) if (midi - last as f64).abs() * 100.0 <= 50.0 + self.hysteresis_cents => {
+                last
+            }
+            _ => nearest,
+        };
+
+        self.last = Some(note);
+        Some(note)
+    }
+
+    /// Returns the most recently emitted note, if any.
+    #[must_use]
+    pub fn last(&self) -> Option<u8> {
+        self.last
+    }
+
+    /// Forgets the held note, so the next pitch snaps to its nearest note.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
@@ -62,3 +62,1236 @@ fn test_try_from_midi_number() {
         assert_eq!(pitch.try_midi_number().unwrap(), midi);
     }
 }
+
+#[test]
+fn test_midi_number_with_cents() {
+    let (midi, cents) = Pitch::new(440.0).midi_number_with_cents().unwrap();
+    assert_eq!(midi, 69);
+    assert!(cents.abs() < 1e-9);
+
+    // 450 Hz is ~38.9 cents sharp of A4
+    let (midi, cents) = Pitch::new(450.0).midi_number_with_cents().unwrap();
+    assert_eq!(midi, 69);
+    assert!((cents - 38.906).abs() < 0.01);
+
+    assert!(Pitch::new(1.0).midi_number_with_cents().is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_pitch_cache() {
+    use crate::PitchCache;
+
+    let mut cache = PitchCache::new();
+    let first = cache.get_or_parse("C#4").unwrap();
+    let second = cache.get_or_parse("C#4").unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get_or_parse("H4").is_err());
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_bits_roundtrip() {
+    for (_midi, name, _octave, _hz) in NOTE_DATASETS {
+        let pitch = Pitch::from_str(name).unwrap();
+        let restored = Pitch::from_bits(pitch.to_bits());
+        assert_eq!(pitch.frequency().to_bits(), restored.frequency().to_bits());
+    }
+}
+
+#[test]
+fn test_untranspose() {
+    let a4 = Pitch::new(440.0);
+    assert_eq!(a4.transpose(7.0).untranspose(7.0), a4);
+    assert_eq!(a4.transpose(12.0).untranspose(12.0), a4);
+
+    for midi in 0..=127 {
+        let pitch = Pitch::try_from_midi_number(midi).unwrap();
+        for semitones in [-13.0, -1.0, 0.5, 1.0, 7.0, 12.0] {
+            let restored = pitch.transpose(semitones).untranspose(semitones);
+            let ulps = restored
+                .frequency()
+                .to_bits()
+                .abs_diff(pitch.frequency().to_bits());
+            assert!(ulps <= 1, "MIDI {midi} by {semitones}: off by {ulps} ulps");
+        }
+    }
+}
+
+#[test]
+fn test_clamped_to_midi_range() {
+    let lowest = Pitch::try_from_midi_number(0).unwrap();
+    let highest = Pitch::try_from_midi_number(127).unwrap();
+
+    assert_eq!(Pitch::new(5.0).clamped_to_midi_range(), lowest);
+    assert_eq!(Pitch::new(20000.0).clamped_to_midi_range(), highest);
+
+    // Rounds to MIDI 0, but is still below its exact frequency
+    let flat_c = Pitch::new(8.0);
+    assert_eq!(flat_c.try_midi_number(), Ok(0));
+    assert_eq!(flat_c.clamped_to_midi_range(), lowest);
+    let sharp_g = Pitch::new(12600.0);
+    assert_eq!(sharp_g.try_midi_number(), Ok(127));
+    assert_eq!(sharp_g.clamped_to_midi_range(), highest);
+
+    let in_range = Pitch::new(445.0);
+    assert_eq!(in_range.clamped_to_midi_range(), in_range);
+}
+
+#[test]
+fn test_bins_per_octave() {
+    let a4 = Pitch::from_str("A4").unwrap();
+    let a5 = Pitch::from_str("A5").unwrap();
+
+    let mut bins = Pitch::bins_per_octave(a4, 12, 13);
+    assert_eq!(bins.next(), Some(a4));
+    assert_eq!(bins.nth(10).unwrap().try_midi_number().unwrap(), 80);
+    assert_eq!(bins.next(), Some(a5));
+    assert_eq!(bins.next(), None);
+
+    assert_eq!(Pitch::bins_per_octave(a4, 0, 4).count(), 0);
+}
+
+#[test]
+fn test_approx_eq() {
+    let a = Pitch::new(440.0);
+    let b = Pitch::new(440.005);
+
+    assert!(a.approx_eq(&b, 0.01));
+    assert!(!a.approx_eq(&b, 0.001));
+
+    // 441 Hz is ~3.9 cents above 440 Hz
+    let c = Pitch::new(441.0);
+    assert!(a.approx_eq_cents(&c, 4.0));
+    assert!(!a.approx_eq_cents(&c, 3.0));
+    assert!(c.approx_eq_cents(&a, 4.0));
+}
+
+#[test]
+fn test_octave_shifts() {
+    let a4 = Pitch::from_str("A4").unwrap();
+
+    assert_eq!(a4.octave_up().frequency(), 880.0);
+    assert_eq!(a4.octave_down().frequency(), 220.0);
+    assert_eq!(a4.shift_octaves(3).frequency(), 3520.0);
+    assert_eq!(a4.shift_octaves(-4).frequency(), 27.5);
+    assert_eq!(a4.shift_octaves(0), a4);
+    assert_eq!(a4.octave_up().try_midi_number().unwrap(), 81);
+}
+
+#[test]
+fn test_octave_position() {
+    let datasets = [
+        ("C4", 0.0),
+        ("F#4", 0.5),
+        ("C-1", 0.0),
+        ("C9", 0.0),
+        ("D#2", 0.25),
+    ];
+
+    for (name, expected) in datasets {
+        let pitch = Pitch::from_str(name).unwrap();
+        let position = pitch.octave_position();
+        assert!((position - expected).abs() < 1e-9, "{name}: {position}");
+    }
+
+    let b3 = Pitch::from_str("B3").unwrap();
+    assert!((b3.octave_position() - 11.0 / 12.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_frequency_units() {
+    let a4 = Pitch::from_str("A4").unwrap();
+
+    assert!((a4.frequency_khz() - 0.44).abs() < 1e-12);
+    assert_eq!(a4.frequency_millihz(), 440_000.0);
+    assert!((a4.cents_from_c0() - 6900.0).abs() < 1e-9);
+
+    let c_minus_one = Pitch::try_from_midi_number(0).unwrap();
+    assert!(c_minus_one.cents_from_c0().abs() < 1e-9);
+}
+
+#[test]
+fn test_transpose_with_ratio() {
+    let a4 = Pitch::from_str("A4").unwrap();
+
+    let (octave, ratio) = a4.transpose_with_ratio(12.0);
+    assert!((ratio - 2.0).abs() < 1e-12);
+    assert_eq!(octave, a4.transpose(12.0));
+
+    let (fifth, ratio) = a4.transpose_with_ratio(7.0);
+    assert!((ratio - 1.4983).abs() < 1e-4);
+    assert_eq!(fifth.frequency(), a4.frequency() * ratio);
+}
+
+#[test]
+fn test_out_of_midi_range_sign() {
+    use crate::{Accidental, Note, NoteLetter, PitchyError};
+
+    assert_eq!(
+        Pitch::from_str("C-3"),
+        Err(PitchyError::OutOfMidiRange(-24))
+    );
+    assert_eq!(Pitch::from_str("A9"), Err(PitchyError::OutOfMidiRange(129)));
+    assert_eq!(
+        Pitch::try_from(Note::new(NoteLetter::D, Accidental::Natural, -3)),
+        Err(PitchyError::OutOfMidiRange(-22))
+    );
+    assert_eq!(
+        Pitch::try_from(Note::new(NoteLetter::C, Accidental::Natural, 50)),
+        Err(PitchyError::OutOfMidiRange(612))
+    );
+    assert_eq!(
+        Pitch::try_from_midi_number(200),
+        Err(PitchyError::OutOfMidiRange(200))
+    );
+    assert_eq!(
+        Pitch::new(1.0).try_midi_number(),
+        Err(PitchyError::OutOfMidiRange(-36))
+    );
+}
+
+#[test]
+fn test_snap_to_scale() {
+    use crate::PitchyError;
+
+    const MAJOR: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+    // 470 Hz sits between A#4 and B4, closer to B4
+    let snapped = Pitch::new(470.0).snap_to_scale(&MAJOR, 0).unwrap();
+    assert_eq!(snapped.try_midi_number().unwrap(), 71);
+
+    // In-scale pitches snap to themselves
+    let e4 = Pitch::from_str("E4").unwrap();
+    assert_eq!(e4.transpose(0.3).snap_to_scale(&MAJOR, 0).unwrap(), e4);
+
+    // C#4 is equidistant from C4 and D4 in C major; ties round up
+    let c_sharp4 = Pitch::from_str("C#4").unwrap();
+    let snapped = c_sharp4.snap_to_scale(&MAJOR, 0).unwrap();
+    assert_eq!(snapped.try_midi_number().unwrap(), 62);
+
+    // ...but C#4 belongs to D major
+    assert_eq!(c_sharp4.snap_to_scale(&MAJOR, 2).unwrap(), c_sharp4);
+
+    assert_eq!(
+        Pitch::new(440.0).snap_to_scale(&[], 0),
+        Err(PitchyError::InvalidScale)
+    );
+    assert!(Pitch::new(1.0).snap_to_scale(&MAJOR, 0).is_err());
+}
+
+#[test]
+fn test_sweep_into() {
+    let start = Pitch::new(100.0);
+    let end = Pitch::new(900.0);
+    let mut buffer = [0.0; 5];
+    Pitch::sweep_into(start, end, &mut buffer);
+
+    assert!((buffer[0] - 100.0).abs() < 1e-9);
+    assert!((buffer[4] - 900.0).abs() < 1e-9);
+    // The midpoint is the geometric mean of the endpoints
+    assert!((buffer[2] - 300.0).abs() < 1e-9);
+
+    let mut single = [0.0; 1];
+    Pitch::sweep_into(start, end, &mut single);
+    assert_eq!(single, [100.0]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sweep() {
+    let sweep = Pitch::sweep(Pitch::new(100.0), Pitch::new(900.0), 5);
+    let mut buffer = [0.0; 5];
+    Pitch::sweep_into(Pitch::new(100.0), Pitch::new(900.0), &mut buffer);
+
+    assert_eq!(sweep, buffer);
+    assert!(Pitch::sweep(Pitch::new(100.0), Pitch::new(900.0), 0).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_glissando() {
+    let a4 = Pitch::new(440.0);
+    let a5 = Pitch::new(880.0);
+
+    let gliss: Vec<Pitch> = Pitch::glissando(&a4, &a5, 13).collect();
+    assert_eq!(gliss.len(), 13);
+    assert_eq!(gliss[0], a4);
+    assert!((gliss[12].frequency() - 880.0).abs() < 1e-9);
+    for pair in gliss.windows(2) {
+        assert!((pair[0].cents_to(&pair[1]) - 100.0).abs() < 1e-9);
+    }
+
+    // The midpoint is six semitones above A4, exactly on D#5
+    let (midi, cents) = gliss[6].midi_number_with_cents().unwrap();
+    assert_eq!(midi, 75);
+    assert!(cents.abs() < 1e-9);
+
+    let down: Vec<Pitch> = Pitch::glissando(&a5, &a4, 3).collect();
+    assert!((down[1].frequency() - 622.254).abs() < 1e-3);
+    assert!((down[2].frequency() - 440.0).abs() < 1e-9);
+
+    assert!(Pitch::glissando(&a4, &a5, 1).eq([a4]));
+    assert_eq!(Pitch::glissando(&a4, &a5, 0).count(), 0);
+}
+
+#[test]
+fn test_from_midi_slice_into() {
+    use crate::PitchyError;
+
+    let mut out = [Pitch::new(0.0); 3];
+    assert_eq!(Pitch::from_midi_slice_into(&[57, 69, 81], &mut out), Ok(3));
+    assert_eq!(out[1].frequency(), 440.0);
+
+    let mut short = [Pitch::new(0.0); 2];
+    assert_eq!(
+        Pitch::from_midi_slice_into(&[57, 69, 81], &mut short),
+        Ok(2)
+    );
+
+    assert_eq!(
+        Pitch::from_midi_slice_into(&[60, 200, 62], &mut out),
+        Err((1, PitchyError::OutOfMidiRange(200)))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_from_midi_slice() {
+    use crate::PitchyError;
+
+    let pitches = Pitch::from_midi_slice(&[57, 69, 81]).unwrap();
+    let frequencies: Vec<f64> = pitches.iter().map(Pitch::frequency).collect();
+    assert_eq!(frequencies, [220.0, 440.0, 880.0]);
+
+    assert_eq!(
+        Pitch::from_midi_slice(&[60, 62, 200, 255]),
+        Err((2, PitchyError::OutOfMidiRange(200)))
+    );
+    assert_eq!(Pitch::from_midi_slice(&[]), Ok(Vec::new()));
+}
+
+#[test]
+fn test_nearest_note_with_ref() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    let a4 = Note::new(NoteLetter::A, Accidental::Natural, 4);
+
+    let (note, cents) = Pitch::new(442.0).nearest_note_with_ref(442.0).unwrap();
+    assert_eq!(note, a4);
+    assert!(cents.abs() < 1e-9);
+
+    // The same tone is ~7.85 cents sharp against a 440 Hz reference
+    let (note, cents) = Pitch::new(442.0).nearest_note_with_ref(440.0).unwrap();
+    assert_eq!(note, a4);
+    assert!((cents - 7.85).abs() < 0.01);
+
+    let (note, _) = Pitch::new(468.0).nearest_note_with_ref(442.0).unwrap();
+    assert_eq!(note, Note::new(NoteLetter::A, Accidental::Sharp, 4));
+
+    assert!(Pitch::new(1.0).nearest_note_with_ref(442.0).is_none());
+}
+
+#[test]
+fn test_is_in_tune() {
+    // 441 Hz is ~3.9 cents sharp of A4
+    assert!(Pitch::new(441.0).is_in_tune(10.0));
+    assert!(!Pitch::new(441.0).is_in_tune(2.0));
+    assert!(Pitch::new(440.0).is_in_tune(0.0));
+    assert!(!Pitch::new(20000.0).is_in_tune(50.0));
+}
+
+#[test]
+fn test_from_str_long_names() {
+    use crate::PitchyError;
+
+    let datasets = [
+        ("D𝄫-1", 0),
+        ("C♯-1", 1),
+        ("E♭4", 63),
+        ("F𝄪3", 55),
+        ("Gbb4", 65),
+        ("Ax4", 71),
+        ("C##-1", 2),
+        ("  db4 ", 61),
+        ("DB4", 61),
+    ];
+
+    for (name, midi) in datasets {
+        let pitch = Pitch::from_str(name).unwrap();
+        assert_eq!(pitch.try_midi_number().unwrap(), midi, "{name}");
+    }
+
+    // Long but structurally valid names are range-checked rather than rejected by length
+    assert_eq!(
+        Pitch::from_str("C𝄫-1"),
+        Err(PitchyError::OutOfMidiRange(-2))
+    );
+    assert_eq!(Pitch::from_str("C###4"), Err(PitchyError::InvalidName));
+    assert_eq!(Pitch::from_str("H4"), Err(PitchyError::InvalidName));
+    assert_eq!(Pitch::from_str("C#"), Err(PitchyError::InvalidOctave));
+    assert_eq!(Pitch::from_str(""), Err(PitchyError::InvalidName));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pitch_table() {
+    use crate::pitch_table;
+
+    let pitches = [
+        Pitch::from_str("A4").unwrap(),
+        Pitch::new(270.0),
+        Pitch::from_str("G9").unwrap(),
+        Pitch::new(2.0),
+    ];
+
+    let expected = "\
+Note   MIDI          Hz    Cents
+A4       69      440.00    +0.00
+C#4      61      270.00   -45.45
+G9      127    12543.85    +0.00
+-         -        2.00        -
+";
+    assert_eq!(pitch_table(&pitches), expected);
+}
+
+#[test]
+fn test_midi_number_rounded() {
+    use crate::{PitchyError, RoundingMode};
+
+    // A quarter-tone above A4, halfway to A#4
+    let halfway = Pitch::from_str("A4").unwrap().transpose(0.5);
+    assert_eq!(halfway.midi_number_rounded(RoundingMode::Floor), Ok(69));
+    assert_eq!(halfway.midi_number_rounded(RoundingMode::Ceil), Ok(70));
+
+    for midi in 0..=127 {
+        let pitch = Pitch::try_from_midi_number(midi).unwrap();
+        for mode in [
+            RoundingMode::Nearest,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+        ] {
+            assert_eq!(pitch.midi_number_rounded(mode), Ok(midi), "{mode:?}");
+        }
+    }
+
+    let g9 = Pitch::from_str("G9").unwrap();
+    assert_eq!(
+        g9.transpose(0.1).midi_number_rounded(RoundingMode::Ceil),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+    assert_eq!(
+        g9.transpose(0.1).midi_number_rounded(RoundingMode::Nearest),
+        Ok(127)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ordered_pitch_btree_set() {
+    use crate::OrderedPitch;
+    use std::collections::BTreeSet;
+
+    let names = ["G4", "C4", "A#1", "G9", "C4", "C#-1"];
+    let set: BTreeSet<OrderedPitch> = names
+        .iter()
+        .map(|name| OrderedPitch::from(Pitch::from_str(name).unwrap()))
+        .collect();
+
+    let midis: Vec<u8> = set
+        .into_iter()
+        .map(|ordered| Pitch::from(ordered).try_midi_number().unwrap())
+        .collect();
+    assert_eq!(midis, [1, 34, 60, 67, 127]);
+}
+
+#[test]
+fn test_transpose_snapped() {
+    let b4 = Pitch::try_from_midi_number(71).unwrap();
+    assert_eq!(Pitch::new(443.0).transpose_snapped(2.0), b4);
+    assert_eq!(Pitch::new(437.0).transpose_snapped(2.0), b4);
+
+    let a4 = Pitch::from_str("A4").unwrap();
+    assert_eq!(a4.transpose_snapped(0.4), a4);
+    assert_eq!(a4.transpose_snapped(-12.0).frequency(), 220.0);
+}
+
+#[test]
+fn test_nearest_available() {
+    use crate::{Accidental, Note, NoteLetter};
+
+    // C major diatonic subset, as on a diatonic harmonica
+    let available = [
+        NoteLetter::C,
+        NoteLetter::D,
+        NoteLetter::E,
+        NoteLetter::F,
+        NoteLetter::G,
+        NoteLetter::A,
+        NoteLetter::B,
+    ]
+    .map(|letter| Note::new(letter, Accidental::Natural, 4));
+
+    let (note, cents) = Pitch::new(440.0).nearest_available(&available).unwrap();
+    assert_eq!(note.letter(), NoteLetter::A);
+    assert!(cents.abs() < 1e-9);
+
+    // 300 Hz (~D4 + 37 cents) sits between D4 and D#4; only D4 is available
+    let (note, cents) = Pitch::new(300.0).nearest_available(&available).unwrap();
+    assert_eq!(note.letter(), NoteLetter::D);
+    assert!(cents > 30.0 && cents < 40.0);
+
+    // G#4 is 100 cents from both G4 and A4; the first listed wins
+    let g_sharp4 = Pitch::from_str("G#4").unwrap();
+    let (note, cents) = g_sharp4.nearest_available(&available).unwrap();
+    assert_eq!(note.letter(), NoteLetter::G);
+    assert!((cents - 100.0).abs() < 1e-9);
+
+    assert!(Pitch::new(440.0).nearest_available(&[]).is_none());
+}
+
+#[test]
+fn test_nan_ordering() {
+    use crate::OrderedPitch;
+    use core::cmp::Ordering;
+
+    let nan = Pitch::new(f64::NAN);
+    let negative_nan = Pitch::new(-f64::NAN);
+    let a4 = Pitch::new(440.0);
+    let a5 = Pitch::new(880.0);
+
+    assert!(!nan.is_finite());
+    assert!(!Pitch::new(f64::INFINITY).is_finite());
+    assert!(a4.is_finite());
+
+    let mut pitches = [a5, nan, a4, negative_nan, Pitch::new(f64::INFINITY)];
+    pitches.sort_by(Pitch::total_cmp);
+    assert_eq!(pitches[0], a4);
+    assert_eq!(pitches[1], a5);
+    assert_eq!(pitches[2].frequency(), f64::INFINITY);
+    assert!(pitches[3].frequency().is_nan() && pitches[4].frequency().is_nan());
+
+    assert_eq!(nan.total_cmp(&negative_nan), Ordering::Equal);
+    assert_eq!(a4.min(nan), a4);
+    assert_eq!(nan.min(a4), a4);
+    assert!(a4.max(nan).frequency().is_nan());
+    assert_eq!(a4.max(a5), a5);
+
+    assert_eq!(OrderedPitch::from(nan), OrderedPitch::from(negative_nan));
+    assert!(OrderedPitch::from(a5) < OrderedPitch::from(negative_nan));
+}
+
+#[test]
+fn test_ratio_to() {
+    let a4 = Pitch::from_str("A4").unwrap();
+    let a5 = Pitch::from_str("A5").unwrap();
+    let e5 = Pitch::from_str("E5").unwrap();
+
+    assert_eq!(a4.ratio_to(&a5), Some(2.0));
+    assert_eq!(a5.ratio_to(&a4), Some(0.5));
+    assert!((a4.ratio_to(&e5).unwrap() - 1.498).abs() < 0.001);
+    assert_eq!(Pitch::new(0.0).ratio_to(&a4), None);
+}
+
+#[test]
+fn test_chained_transpose_stays_on_grid() {
+    let mut pitch = Pitch::try_from_midi_number(0).unwrap();
+
+    for midi in 1..=127 {
+        pitch = pitch.transpose(1.0);
+
+        let (number, cents) = pitch.midi_number_with_cents().unwrap();
+        assert_eq!(number, midi, "{pitch:?}");
+        assert!(cents.abs() < 1e-6, "midi {midi} drifted by {cents} cents");
+    }
+
+    let g9 = Pitch::try_from_midi_number(127).unwrap();
+    assert!(pitch.approx_eq_cents(&g9, 1e-6));
+}
+
+#[test]
+fn test_transpose_saturating() {
+    let g9 = Pitch::try_from_midi_number(127).unwrap();
+    let c_minus1 = Pitch::try_from_midi_number(0).unwrap();
+
+    let up = g9.transpose_saturating(5.0);
+    assert_eq!(up.frequency(), g9.frequency());
+    assert_eq!(up.try_midi_number().unwrap(), 127);
+
+    assert_eq!(c_minus1.transpose_saturating(-12.0), c_minus1);
+
+    let a4 = Pitch::new(440.0);
+    assert_eq!(a4.transpose_saturating(3.0), a4.transpose(3.0));
+}
+
+#[test]
+fn test_detect_note() {
+    let a4 = Pitch::new(440.0).detect_note().unwrap();
+    assert_eq!(a4.note, 69);
+    assert!(a4.cents.abs() < 1e-9);
+    assert!((a4.confidence - 1.0).abs() < 1e-9);
+
+    let sharp = Pitch::new(440.0).transpose(0.25).detect_note().unwrap();
+    assert_eq!(sharp.note, 69);
+    assert!((sharp.cents - 25.0).abs() < 1e-9);
+    assert!((sharp.confidence - 0.5).abs() < 1e-9);
+
+    let flat = Pitch::new(440.0).transpose(-0.4).detect_note().unwrap();
+    assert!((flat.confidence - 0.2).abs() < 1e-9);
+
+    assert_eq!(Pitch::new(5.0).detect_note(), None);
+}
+
+#[test]
+fn test_pitch_is_natural() {
+    let note = |name: &str| Pitch::from_str(name).unwrap();
+
+    assert_eq!(note("C4").is_natural(), Some(true));
+    assert_eq!(note("C#4").is_natural(), Some(false));
+    assert_eq!(note("Bb3").is_natural(), Some(false));
+    // Spelling is ignored: these sound as B3 and F4
+    assert_eq!(note("Cb4").is_natural(), Some(true));
+    assert_eq!(note("E#4").is_natural(), Some(true));
+
+    assert_eq!(Pitch::new(5.0).is_natural(), None);
+}
+
+#[test]
+fn test_quantize_to_cents_grid() {
+    let pitch = Pitch::new(450.0);
+
+    // 450 Hz is 38.9 cents above A4: a quarter-tone grid rounds up to A4 + 50 cents
+    let quarter_tone = pitch.quantize_to_cents_grid(50.0);
+    assert!((quarter_tone.cents_from_c0() - 6950.0).abs() < 1e-9);
+    assert!((quarter_tone.frequency() - 452.893).abs() < 1e-3);
+
+    // A semitone grid matches plain snapping
+    let semitone = pitch.quantize_to_cents_grid(100.0);
+    assert!((semitone.frequency() - 440.0).abs() < 1e-9);
+    assert_eq!(semitone, pitch.transpose_snapped(0.0));
+
+    // Whole tones counted from C-1 skip A4, so the nearest grid point is A#4
+    let whole_tone = pitch.quantize_to_cents_grid(200.0);
+    assert!((whole_tone.cents_from_c0() - 7000.0).abs() < 1e-9);
+    assert_eq!(pitch.quantize_to_cents_grid(0.0), pitch);
+    assert_eq!(pitch.quantize_to_cents_grid(f64::NAN), pitch);
+}
+
+#[test]
+fn test_note_spelling_preference() {
+    use crate::{Accidental, Note, NoteLetter, PitchyError, SpellingPreference};
+
+    let g_flat5 = Note::new(NoteLetter::G, Accidental::Flat, 5);
+    let f_sharp5 = Note::new(NoteLetter::F, Accidental::Sharp, 5);
+    let pitch = Pitch::from_str("Gb5").unwrap();
+
+    // The plain conversion loses the flat spelling
+    assert_eq!(Note::try_from(pitch), Ok(f_sharp5));
+    assert_eq!(
+        pitch.into_note_preferring(SpellingPreference::Flat),
+        Ok(g_flat5)
+    );
+    assert_eq!(
+        pitch.into_note_preferring(SpellingPreference::Sharp),
+        Ok(f_sharp5)
+    );
+
+    assert_eq!(Pitch::parse_as_note("Gb5"), Ok(g_flat5));
+    assert_eq!(Pitch::parse_as_note("F#5"), Ok(f_sharp5));
+    assert_eq!(
+        Pitch::parse_as_note("Gb10"),
+        Err(PitchyError::OutOfMidiRange(138))
+    );
+    assert_eq!(
+        Pitch::new(5.0).into_note_preferring(SpellingPreference::Flat),
+        Err(PitchyError::OutOfMidiRange(-9))
+    );
+}
+
+#[test]
+fn test_midi_number_f64() {
+    let a4 = Pitch::new(440.0);
+    assert_eq!(a4.midi_number_f64(), 69.0);
+    assert!((a4.transpose(0.5).midi_number_f64() - 69.5).abs() < 1e-9);
+    assert!((a4.transpose(-0.25).midi_number_f64() - 68.75).abs() < 1e-9);
+
+    // No rounding to the MIDI range
+    assert!((Pitch::new(220.0 / 64.0).midi_number_f64() + 15.0).abs() < 1e-9);
+    assert!(Pitch::new(0.0).midi_number_f64().is_infinite());
+}
+
+#[test]
+fn test_transpose_checked() {
+    use crate::PitchyError;
+
+    let c_minus1 = Pitch::try_from_midi_number(0).unwrap();
+    assert_eq!(
+        c_minus1.transpose_checked(-1.0),
+        Err(PitchyError::OutOfMidiRange(-1))
+    );
+    assert_eq!(c_minus1.transpose_checked(0.0), Ok(c_minus1));
+
+    let a4 = Pitch::new(440.0);
+    assert_eq!(a4.transpose_checked(12.0), Ok(Pitch::new(880.0)));
+
+    let g9 = Pitch::try_from_midi_number(127).unwrap();
+    assert_eq!(
+        g9.transpose_checked(1.0),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+}
+
+#[test]
+fn test_from_str_any() {
+    let note = |name: &str| Pitch::from_str(name).unwrap();
+
+    let datasets = [
+        ("C4", note("C4")),
+        ("  Db-1 ", note("Db-1")),
+        ("c'", note("C4")),
+        ("c", note("C3")),
+        ("C", note("C2")),
+        ("A,,", note("A0")),
+        ("f#''", note("F#5")),
+        ("bb'", note("Bb4")),
+        ("Eb,", note("Eb1")),
+        ("440", Pitch::new(440.0)),
+        ("261.63 Hz", Pitch::new(261.63)),
+        ("440hz", Pitch::new(440.0)),
+        ("1.2kHz", Pitch::new(1200.0)),
+        (".5 KHZ", Pitch::new(500.0)),
+    ];
+
+    for (input, expected) in datasets {
+        assert_eq!(Pitch::from_str_any(input), Ok(expected), "{input:?}");
+    }
+
+    for input in ["", "0", "440 MHz", "1e999", "c,", "C'", "H4", "kHz", "C10"] {
+        assert!(Pitch::from_str_any(input).is_err(), "{input:?}");
+    }
+}
+
+#[test]
+fn test_pitch_class_from_str() {
+    use crate::PitchyError;
+
+    let datasets = [
+        ("C", 0),
+        ("C#", 1),
+        ("Db", 1),
+        ("Bb", 10),
+        ("b", 11),
+        ("Cb", 11),
+        ("B#", 0),
+        ("F𝄪", 7),
+        (" E♭ ", 3),
+    ];
+
+    for (name, expected) in datasets {
+        assert_eq!(Pitch::pitch_class_from_str(name), Ok(expected), "{name:?}");
+    }
+
+    for name in ["", "H", "C#4", "C$"] {
+        assert_eq!(
+            Pitch::pitch_class_from_str(name),
+            Err(PitchyError::InvalidName),
+            "{name:?}"
+        );
+    }
+    assert!(Pitch::from_str("C#").is_err());
+}
+
+#[test]
+fn test_midi_number_with_cents_at_midpoint() {
+    // Exactly halfway between A4 and A#4
+    let midpoint = Pitch::new(440.0 * crate::math::powf2(0.5 / 12.0));
+    assert_eq!(midpoint.midi_number_f64(), 69.5);
+
+    // The tie rounds up, giving a deviation in [-50, +50)
+    assert_eq!(midpoint.midi_number_with_cents(), Ok((70, -50.0)));
+    assert_eq!(midpoint.try_midi_number(), Ok(70));
+
+    let (midi, cents) = midpoint.transpose(1e-9).midi_number_with_cents().unwrap();
+    assert_eq!(midi, 70);
+    assert!(cents > -50.0 && cents < -49.99, "{cents}");
+
+    let (midi, cents) = midpoint.transpose(-1e-9).midi_number_with_cents().unwrap();
+    assert_eq!(midi, 69);
+    assert!(cents > 49.99 && cents < 50.0, "{cents}");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_format_frequency() {
+    let a4 = Pitch::new(440.0);
+    assert_eq!(a4.format_frequency(0), "440");
+    assert_eq!(a4.format_frequency(1), "440.0");
+    assert_eq!(a4.format_frequency(3), "440.000");
+
+    assert_eq!(Pitch::new(27.5).format_frequency(0), "28");
+    assert_eq!(Pitch::new(12543.853951).format_frequency(2), "12543.85");
+}
+
+#[test]
+fn test_packed_u16_round_trip() {
+    for (midi, _, _, frequency) in NOTE_DATASETS {
+        let packed = Pitch::new(frequency).to_packed_u16().unwrap();
+        assert_eq!(packed >> 9, midi as u16);
+    }
+
+    let a4 = Pitch::new(440.0);
+    for cents in [-49.7, -12.3, 0.0, 0.4, 31.6, 49.2] {
+        let pitch = a4.transpose(cents / 100.0);
+        let restored = Pitch::from_packed_u16(pitch.to_packed_u16().unwrap());
+        assert!(restored.approx_eq_cents(&pitch, 0.5), "{cents} cents");
+    }
+
+    let flat = a4.transpose(-0.2).to_packed_u16().unwrap();
+    assert_eq!(flat, 69 << 9 | (-20i16 as u16 & 0x1FF));
+
+    // Offsets beyond the quarter-tone still decode within the ±256-cent range
+    let below_c4 = Pitch::from_packed_u16(60 << 9 | (-256i16 as u16 & 0x1FF));
+    assert!(below_c4.approx_eq_cents(
+        &Pitch::try_from_midi_number(60).unwrap().transpose(-2.56),
+        1e-9
+    ));
+
+    assert_eq!(Pitch::new(5.0).to_packed_u16(), None);
+    assert_eq!(Pitch::new(f64::NAN).to_packed_u16(), None);
+}
+
+#[test]
+fn test_octave_checked() {
+    use crate::PitchyError;
+
+    for (_, _, octave, frequency) in NOTE_DATASETS {
+        assert_eq!(Pitch::new(frequency).octave_checked(), Ok(octave));
+    }
+
+    assert_eq!(
+        Pitch::new(2.0).octave_checked(),
+        Err(PitchyError::OutOfMidiRange(-24))
+    );
+    assert_eq!(
+        Pitch::new(30000.0).octave_checked(),
+        Err(PitchyError::OutOfMidiRange(142))
+    );
+    assert_eq!(Pitch::new(2.0).octave(), None);
+}
+
+#[test]
+fn test_transpose_all() {
+    let original = NOTE_DATASETS.map(|(_, _, _, frequency)| Pitch::new(frequency));
+
+    for semitones in [-13.5, -1.0, 0.0, 0.25, 7.0, 24.0] {
+        let mut pitches = original;
+        crate::transpose_all(&mut pitches, semitones);
+
+        for (transposed, pitch) in pitches.iter().zip(original) {
+            assert_eq!(*transposed, pitch.transpose(semitones));
+        }
+    }
+
+    crate::transpose_all(&mut [], 3.0);
+}
+
+#[test]
+fn test_octave_checked_at_range_edges() {
+    use crate::PitchyError;
+
+    let c_minus1 = Pitch::try_from_midi_number(0).unwrap();
+    let g9 = Pitch::try_from_midi_number(127).unwrap();
+
+    assert_eq!(c_minus1.octave_checked(), Ok(-1));
+    assert_eq!(c_minus1.transpose(-0.49).octave_checked(), Ok(-1));
+    assert_eq!(
+        c_minus1.transpose(-0.51).octave_checked(),
+        Err(PitchyError::OutOfMidiRange(-1))
+    );
+
+    assert_eq!(g9.octave_checked(), Ok(9));
+    assert_eq!(g9.transpose(0.49).octave_checked(), Ok(9));
+    assert_eq!(
+        g9.transpose(0.51).octave_checked(),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_nearest_note_name() {
+    assert_eq!(Pitch::new(445.0).nearest_note_name().as_deref(), Some("A4"));
+    assert_eq!(
+        Pitch::new(277.0).nearest_note_name().as_deref(),
+        Some("C#4")
+    );
+    assert_eq!(Pitch::new(2.0).nearest_note_name(), None);
+
+    for (_, name, _, frequency) in NOTE_DATASETS {
+        let mut written = String::new();
+        let result = Pitch::new(frequency).write_nearest_note_name(&mut written);
+        assert_eq!(result, Some(Ok(())));
+        assert_eq!(written, name);
+    }
+
+    let mut untouched = String::new();
+    assert_eq!(
+        Pitch::new(30000.0).write_nearest_note_name(&mut untouched),
+        None
+    );
+    assert!(untouched.is_empty());
+}
+
+#[test]
+fn test_write_nearest_note_name_into_fixed_buffer() {
+    struct Buffer<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> core::fmt::Write for Buffer<N> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > N {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut buffer = Buffer {
+        bytes: [0; 8],
+        len: 0,
+    };
+    assert_eq!(
+        Pitch::new(277.0).write_nearest_note_name(&mut buffer),
+        Some(Ok(()))
+    );
+    assert_eq!(&buffer.bytes[..buffer.len], b"C#4");
+
+    let mut too_small = Buffer {
+        bytes: [0; 2],
+        len: 0,
+    };
+    assert_eq!(
+        Pitch::new(277.0).write_nearest_note_name(&mut too_small),
+        Some(Err(core::fmt::Error))
+    );
+
+    let mut untouched = Buffer {
+        bytes: [0; 8],
+        len: 0,
+    };
+    assert_eq!(
+        Pitch::new(30000.0).write_nearest_note_name(&mut untouched),
+        None
+    );
+    assert_eq!(untouched.len, 0);
+}
+
+#[test]
+fn test_transpose_within_octave() {
+    use crate::PitchyError;
+
+    let note = |name: &str| Pitch::from_str(name).unwrap();
+
+    let datasets = [
+        ("B4", 2, 4, "C#4"),
+        ("C4", -1, 4, "B4"),
+        ("C4", 0, 4, "C4"),
+        ("A4", 27, 2, "C2"),
+        ("G2", -20, 5, "B5"),
+        ("A#4", 14, -1, "C-1"),
+    ];
+
+    for (start, semitones, base_octave, expected) in datasets {
+        let folded = note(start)
+            .transpose_within_octave(semitones, base_octave)
+            .unwrap();
+        assert!(
+            folded.approx_eq(&note(expected), 1e-9),
+            "{start} + {semitones}"
+        );
+        assert_eq!(folded.octave(), Some(base_octave));
+    }
+
+    // Detuning survives the fold
+    let sharp_b4 = note("B4").transpose(0.3);
+    let folded = sharp_b4.transpose_within_octave(2, 4).unwrap();
+    assert!(folded.approx_eq_cents(&note("C#4").transpose(0.3), 1e-9));
+
+    assert_eq!(
+        note("A4").transpose_within_octave(0, 9),
+        Err(PitchyError::OutOfMidiRange(129))
+    );
+
+    for frequency in [0.0, -440.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            Pitch::new(frequency).transpose_within_octave(2, 4),
+            Err(PitchyError::InvalidFrequency),
+            "{frequency} Hz"
+        );
+    }
+    // Transposing so far down that the frequency underflows to zero
+    assert_eq!(
+        note("A4").transpose_within_octave(-20_000, 4),
+        Err(PitchyError::InvalidFrequency)
+    );
+    assert_eq!(
+        note("A4").transpose_within_octave(i32::MAX, 4),
+        Err(PitchyError::InvalidFrequency)
+    );
+}
+
+#[test]
+fn test_retune() {
+    assert_eq!(Pitch::new(440.0).retune(440.0, 442.0).frequency(), 442.0);
+    assert_eq!(Pitch::new(220.0).retune(440.0, 442.0).frequency(), 221.0);
+
+    let c4 = Pitch::from_str("C4").unwrap();
+    let retuned = c4.retune(440.0, 415.0);
+    let (note, cents) = retuned.nearest_note_with_ref(415.0).unwrap();
+    assert_eq!(note, crate::Note::try_from(c4).unwrap());
+    assert!(cents.abs() < 1e-9);
+    assert!(retuned.retune(415.0, 440.0).approx_eq(&c4, 1e-9));
+}
+
+#[test]
+fn test_pitch_quantizer_hysteresis() {
+    use crate::PitchQuantizer;
+
+    let a4 = Pitch::new(440.0);
+    // Jitter of ±8 cents around the A4/A#4 midpoint
+    let jitter = [0.42, 0.58, 0.45, 0.55, 0.47, 0.53, 0.42, 0.58];
+
+    let naive = jitter.map(|offset| a4.transpose(offset).try_midi_number().unwrap());
+    let naive_switches = naive.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    assert_eq!(naive_switches, jitter.len() - 1);
+
+    let mut quantizer = PitchQuantizer::new(10.0);
+    assert_eq!(quantizer.process(a4), Some(69));
+    for offset in jitter {
+        assert_eq!(
+            quantizer.process(a4.transpose(offset)),
+            Some(69),
+            "{offset}"
+        );
+    }
+
+    // A clear move past the band switches once, and the jitter now holds A#4
+    assert_eq!(quantizer.process(a4.transpose(1.0)), Some(70));
+    for offset in jitter {
+        assert_eq!(
+            quantizer.process(a4.transpose(offset)),
+            Some(70),
+            "{offset}"
+        );
+    }
+
+    assert_eq!(quantizer.process(Pitch::new(2.0)), None);
+    assert_eq!(quantizer.last(), None);
+    assert_eq!(quantizer.process(a4.transpose(0.45)), Some(69));
+
+    quantizer.reset();
+    assert_eq!(quantizer.process(a4.transpose(0.55)), Some(70));
+}
+
+#[test]
+fn test_nearest_in() {
+    let samples = [Pitch::new(220.0), Pitch::new(440.0), Pitch::new(880.0)];
+
+    let datasets = [
+        (100.0, Some(0)),
+        (220.0, Some(0)),
+        (300.0, Some(0)),
+        (320.0, Some(1)),
+        (440.0, Some(1)),
+        (620.0, Some(1)),
+        (630.0, Some(2)),
+        (5000.0, Some(2)),
+    ];
+
+    for (frequency, expected) in datasets {
+        assert_eq!(
+            Pitch::new(frequency).nearest_in(&samples),
+            expected,
+            "{frequency} Hz"
+        );
+    }
+
+    // The geometric midpoint between A3 and A4 is equally far in cents; the higher wins
+    let midpoint = Pitch::new(220.0).transpose(6.0);
+    assert_eq!(midpoint.nearest_in(&samples[..2]), Some(1));
+
+    assert_eq!(Pitch::new(440.0).nearest_in(&[]), None);
+}
+
+#[test]
+fn test_midi_frequency_table() {
+    use crate::MIDI_FREQUENCIES;
+
+    for (midi, &frequency) in MIDI_FREQUENCIES.iter().enumerate() {
+        let computed = super::midi_frequency(midi as f64);
+        assert!((frequency - computed).abs() < 1e-9, "MIDI {midi}");
+        assert_eq!(
+            Pitch::try_from_midi_number(midi as u8).unwrap().frequency(),
+            frequency
+        );
+    }
+}
+
+#[test]
+fn test_midi_frequency_bounds() {
+    let lowest = Pitch::try_from_midi_number(0).unwrap().frequency();
+    let highest = Pitch::try_from_midi_number(127).unwrap().frequency();
+
+    assert_eq!(Pitch::MIN_MIDI_FREQUENCY, lowest);
+    assert_eq!(Pitch::MAX_MIDI_FREQUENCY, highest);
+
+    assert_eq!(
+        Pitch::new(Pitch::MIN_MIDI_FREQUENCY).try_midi_number(),
+        Ok(0)
+    );
+    assert_eq!(
+        Pitch::new(Pitch::MAX_MIDI_FREQUENCY).try_midi_number(),
+        Ok(127)
+    );
+
+    assert_eq!(Pitch::midi_range(), 0..=127);
+    assert!(Pitch::midi_range().all(|midi| Pitch::try_from_midi_number(midi).is_ok()));
+}
+
+#[test]
+fn test_semitone_operators() {
+    let c4 = Pitch::from_str("C4").unwrap();
+    let c5 = Pitch::from_str("C5").unwrap();
+    let g4 = Pitch::from_str("G4").unwrap();
+
+    assert_eq!((c4 + 12).frequency(), c5.frequency());
+    assert_eq!((c5 - 12).frequency(), c4.frequency());
+    assert!((c4 + 7).approx_eq(&g4, 1e-9));
+    assert!((g4 - 7).approx_eq(&c4, 1e-9));
+
+    assert_eq!(c4 + 0.5, c4.transpose(0.5));
+    assert_eq!(c4 - 0.5, c4.transpose(-0.5));
+    assert!((c4 + 12.0).approx_eq(&c5, 1e-9));
+}
+
+#[test]
+fn test_fft_bins() {
+    const FFT_SIZE: usize = 4096;
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    let a4 = Pitch::new(440.0);
+    let bin = a4.nearest_fft_bin(FFT_SIZE, SAMPLE_RATE);
+    assert_eq!(bin, 41);
+
+    let resolution = SAMPLE_RATE / FFT_SIZE as f64;
+    let center = Pitch::from_fft_bin(bin, FFT_SIZE, SAMPLE_RATE);
+    assert!((center.frequency() - a4.frequency()).abs() <= resolution / 2.0);
+    assert_eq!(
+        Pitch::from_fft_bin(40, FFT_SIZE, SAMPLE_RATE).nearest_fft_bin(FFT_SIZE, SAMPLE_RATE),
+        40
+    );
+    assert_eq!(
+        Pitch::from_fft_bin(0, FFT_SIZE, SAMPLE_RATE).frequency(),
+        0.0
+    );
+    assert_eq!(
+        Pitch::from_fft_bin(FFT_SIZE / 2, FFT_SIZE, SAMPLE_RATE).frequency(),
+        22050.0
+    );
+
+    assert_eq!(Pitch::new(-5.0).nearest_fft_bin(FFT_SIZE, SAMPLE_RATE), 0);
+    assert_eq!(
+        Pitch::new(f64::NAN).nearest_fft_bin(FFT_SIZE, SAMPLE_RATE),
+        0
+    );
+}
+
+#[test]
+fn test_octave_from_frequency() {
+    for midi in Pitch::midi_range() {
+        let pitch = Pitch::try_from_midi_number(midi).unwrap();
+        for detune in [-0.49, 0.0, 0.49] {
+            let detuned = pitch.transpose(detune);
+            assert_eq!(
+                Some(detuned.octave_from_frequency() as i8),
+                detuned.octave()
+            );
+        }
+    }
+
+    // Beyond the MIDI range the octave keeps counting
+    assert_eq!(Pitch::new(16000.0).octave_from_frequency(), 9);
+    assert_eq!(Pitch::new(30000.0).octave_from_frequency(), 10);
+    assert_eq!(Pitch::new(2.0).octave_from_frequency(), -3);
+    assert_eq!(
+        Pitch::from_str("B8")
+            .unwrap()
+            .transpose(0.6)
+            .octave_from_frequency(),
+        9
+    );
+
+    // Degenerate frequencies saturate instead of overflowing
+    assert_eq!(Pitch::new(0.0).octave_from_frequency(), i32::MIN);
+    assert_eq!(Pitch::new(f64::INFINITY).octave_from_frequency(), i32::MAX);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_pitch_iter_transpose() {
+    use crate::PitchIterExt;
+
+    let phrase = vec![Pitch::new(220.0), Pitch::new(261.63), Pitch::new(329.63)];
+    let octave_up: Vec<Pitch> = phrase.iter().copied().transpose(12.0).collect();
+    assert_eq!(
+        octave_up,
+        [Pitch::new(440.0), Pitch::new(523.26), Pitch::new(659.26)]
+    );
+
+    let mut transposed = phrase.clone();
+    crate::transpose_all(&mut transposed, -3.5);
+    assert!(phrase.into_iter().transpose(-3.5).eq(transposed));
+}
+
+#[test]
+fn test_relative_to() {
+    let scientific_c = Pitch::new(256.0);
+
+    let (ratio, cents) = Pitch::new(384.0).relative_to(&scientific_c).unwrap();
+    assert_eq!(ratio, 1.5);
+    assert!((cents - 701.955).abs() < 1e-3);
+
+    let (ratio, cents) = Pitch::new(128.0).relative_to(&scientific_c).unwrap();
+    assert_eq!((ratio, cents), (0.5, -1200.0));
+
+    assert_eq!(scientific_c.relative_to(&scientific_c), Some((1.0, 0.0)));
+    assert_eq!(scientific_c.relative_to(&Pitch::new(0.0)), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_all_octaves_of() {
+    // A-1 (MIDI 9) through A8 (MIDI 117); A9 would be MIDI 129
+    let a_pitches = Pitch::all_octaves_of(9);
+    assert_eq!(a_pitches.len(), 10);
+    assert!(a_pitches.contains(&Pitch::new(440.0)));
+    assert!(
+        a_pitches
+            .iter()
+            .all(|pitch| pitch.try_midi_number().unwrap() % 12 == 9)
+    );
+
+    let cs: Vec<u8> = Pitch::octaves_of(0)
+        .map(|pitch| pitch.try_midi_number().unwrap())
+        .collect();
+    assert_eq!(cs, [0, 12, 24, 36, 48, 60, 72, 84, 96, 108, 120]);
+    assert_eq!(Pitch::octaves_of(7).count(), 11);
+    assert_eq!(Pitch::octaves_of(21).count(), 10);
+}
@@ -0,0 +1,89 @@
+//! Octave-less pitch classes with wrapping mod-12 arithmetic.
+//!
+//! A [`PitchClass`] identifies a note ignoring octave and spelling: C♯4, D♭2 and
+//! C♯7 all share pitch class 1. This is the usual basis for scale and chord logic.
+#[cfg(test)]
+mod tests;
+
+use core::ops::{Add, Sub};
+
+use crate::{Note, Pitch, math::*};
+
+/// A pitch class from 0 to 11, where C = 0, C♯/D♭ = 1, …, B = 11.
+///
+/// Adding or subtracting semitones wraps around the octave.
+///
+/// # Examples
+///
+/// ```
+/// use pitchy::PitchClass;
+///
+/// assert_eq!(PitchClass::new(10) + 3, PitchClass::new(1));
+/// assert_eq!(PitchClass::new(2) - 5, PitchClass::new(9));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PitchClass(u8);
+
+impl PitchClass {
+    /// Creates a pitch class, wrapping `value` modulo 12 (so `12` is C again).
+    #[must_use]
+    pub const fn new(value: u8) -> Self {
+        Self(value % 12)
+    }
+
+    /// Returns the pitch class as a number from 0 to 11.
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the ascending distance in semitones (0–11) from this pitch class to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pitchy::PitchClass;
+    ///
+    /// // A up to C is a minor third, C up to A a major sixth
+    /// assert_eq!(PitchClass::new(9).interval_to(&PitchClass::new(0)), 3);
+    /// assert_eq!(PitchClass::new(0).interval_to(&PitchClass::new(9)), 9);
+    /// ```
+    #[must_use]
+    pub const fn interval_to(&self, other: &PitchClass) -> u8 {
+        (other.0 + 12 - self.0) % 12
+    }
+}
+
+impl Add<u8> for PitchClass {
+    type Output = PitchClass;
+
+    fn add(self, semitones: u8) -> PitchClass {
+        PitchClass::new(self.0 + semitones % 12)
+    }
+}
+
+impl Sub<u8> for PitchClass {
+    type Output = PitchClass;
+
+    fn sub(self, semitones: u8) -> PitchClass {
+        PitchClass::new(self.0 + 12 - semitones % 12)
+    }
+}
+
+/// Uses the nearest equal-tempered note, so slightly detuned pitches keep their class.
+///
+/// Pitches outside the MIDI range still have a class; zero, negative and non-finite
+/// frequencies do not, and their result is unspecified.
+impl From<Pitch> for PitchClass {
+    fn from(pitch: Pitch) -> Self {
+        let midi = round(pitch.cents_from_c0() / 100.0) as i64;
+        Self(midi.rem_euclid(12) as u8)
+    }
+}
+
+/// Enharmonic spellings share a class, so B♯3 and C4 are both C.
+impl From<Note> for PitchClass {
+    fn from(note: Note) -> Self {
+        Self(note.chromatic_index().rem_euclid(12) as u8)
+    }
+}
@@ -0,0 +1,40 @@
+use core::str::FromStr;
+
+use crate::{Accidental, Note, NoteLetter, Pitch, PitchClass};
+
+#[test]
+fn test_pitch_class_arithmetic() {
+    assert_eq!(PitchClass::new(10) + 3, PitchClass::new(1));
+    assert_eq!(PitchClass::new(1) - 3, PitchClass::new(10));
+    assert_eq!(PitchClass::new(11) + 255, PitchClass::new(2));
+    assert_eq!(PitchClass::new(0) - 255, PitchClass::new(9));
+    assert_eq!(PitchClass::new(14).value(), 2);
+
+    for a in 0..12 {
+        for b in 0..12 {
+            let (a, b) = (PitchClass::new(a), PitchClass::new(b));
+            assert_eq!(a + a.interval_to(&b), b);
+        }
+    }
+}
+
+#[test]
+fn test_pitch_class_from_pitch_and_note() {
+    let a4 = Pitch::new(440.0);
+    assert_eq!(PitchClass::from(a4).value(), 9);
+    assert_eq!(PitchClass::from(a4.transpose(0.4)).value(), 9);
+    assert_eq!(
+        PitchClass::from(Pitch::from_str("C#-1").unwrap()).value(),
+        1
+    );
+    assert_eq!(PitchClass::from(Pitch::new(6.875)).value(), 9);
+
+    let b_sharp3 = Note::new(NoteLetter::B, Accidental::Sharp, 3);
+    let c_flat4 = Note::new(NoteLetter::C, Accidental::Flat, 4);
+    assert_eq!(PitchClass::from(b_sharp3), PitchClass::new(0));
+    assert_eq!(PitchClass::from(c_flat4), PitchClass::new(11));
+    assert_eq!(
+        PitchClass::from(Note::new(NoteLetter::A, Accidental::Natural, -3)),
+        PitchClass::from(a4)
+    );
+}
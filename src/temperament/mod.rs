@@ -0,0 +1,120 @@
+//! Pluggable frequency models for mapping MIDI note numbers to frequencies (Hz).
+//!
+//! The [`Temperament`] trait lets callers experiment with alternative tunings
+//! (Pythagorean, meantone, well temperaments, custom tables) while reusing
+//! the rest of the crate. [`EqualTemperament`] reproduces the default behavior.
+#[cfg(test)]
+mod tests;
+
+use crate::{MIDI_FREQUENCIES, Pitch, PitchyError, math::*, note::parse_note};
+
+/// A tuning system that maps MIDI note numbers to frequencies and back.
+pub trait Temperament {
+    /// Returns the frequency in Hertz (Hz) of the given MIDI note number.
+    fn frequency(&self, midi: u8) -> f64;
+
+    /// Returns the (possibly fractional) MIDI note number closest to the given frequency.
+    fn midi_from_frequency(&self, hz: f64) -> f64;
+}
+
+/// Twelve-tone equal temperament anchored at a configurable A4 reference.
+///
+/// `EqualTemperament { a4_hz: 440.0 }` (also the [`Default`]) reads MIDI note frequencies
+/// from [`MIDI_FREQUENCIES`], so it matches [`Pitch::try_from_midi_number`] exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EqualTemperament {
+    /// The frequency of A4 (MIDI 69) in Hertz (Hz).
+    pub a4_hz: f64,
+}
+
+impl Default for EqualTemperament {
+    fn default() -> Self {
+        Self { a4_hz: 440.0 }
+    }
+}
+
+impl Temperament for EqualTemperament {
+    fn frequency(&self, midi: u8) -> f64 {
+        // At the standard reference, read the table so results match `Pitch` bit for bit
+        match MIDI_FREQUENCIES.get(midi as usize) {
+            Some(&frequency) if self.a4_hz == 440.0 => frequency,
+            _ => powf2((midi as f64 - 69.0) / 12.0) * self.a4_hz,
+        }
+    }
+
+    fn midi_from_frequency(&self, hz: f64) -> f64 {
+        69.0 + 12.0 * log2(hz / self.a4_hz)
+    }
+}
+
+/// Runtime tuning state: an A4 reference shared by MIDI and note-name conversions.
+///
+/// Create one from the user's tuning choice and pass it around instead of threading
+/// `a4_hz` through every call. Conversions delegate to [`EqualTemperament`] at the
+/// stored reference; the [`Default`] is A4 = 440 Hz.
+///
+/// # Examples
+///
+/// ```
+/// use pitchy::TuningContext;
+///
+/// let baroque = TuningContext { a4_hz: 415.0 };
+/// let a4 = baroque.pitch_from_str("A4").unwrap();
+/// assert_eq!(a4.frequency(), 415.0);
+/// assert_eq!(baroque.midi_from_pitch(a4), Ok(69));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TuningContext {
+    /// The frequency of A4 (MIDI 69) in Hertz (Hz).
+    pub a4_hz: f64,
+}
+
+impl TuningContext {
+    /// Returns the equal temperament this context delegates to.
+    #[must_use]
+    pub fn temperament(&self) -> EqualTemperament {
+        EqualTemperament { a4_hz: self.a4_hz }
+    }
+
+    /// Creates a pitch from a MIDI note number (0–127) at this context's reference.
+    ///
+    /// Returns `PitchyError::OutOfMidiRange` if the MIDI number is above 127.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn pitch_from_midi(&self, midi: u8) -> Result<Pitch, PitchyError> {
+        Pitch::from_midi_in(midi, &self.temperament())
+    }
+
+    /// Returns the nearest MIDI note number of `pitch` at this context's reference.
+    ///
+    /// Returns `PitchyError::OutOfMidiRange(midi)` with the rounded value if it falls
+    /// outside 0–127.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn midi_from_pitch(&self, pitch: Pitch) -> Result<u8, PitchyError> {
+        let midi = round(self.temperament().midi_from_frequency(pitch.frequency()));
+
+        if (0.0..=127.0).contains(&midi) {
+            Ok(midi as u8)
+        } else {
+            Err(PitchyError::OutOfMidiRange(midi as i16))
+        }
+    }
+
+    /// Parses a note name (e.g., `"A4"`, `"Db-1"`) into a pitch at this context's reference.
+    ///
+    /// Accepts the same syntax as `Pitch::from_str` and fails under the same conditions.
+    #[must_use = "this returns a Result that may hold an error"]
+    pub fn pitch_from_str(&self, s: &str) -> Result<Pitch, PitchyError> {
+        let midi = parse_note(s)?.chromatic_index();
+
+        match u8::try_from(midi) {
+            Ok(midi) if midi <= 127 => self.pitch_from_midi(midi),
+            _ => Err(PitchyError::OutOfMidiRange(midi as i16)),
+        }
+    }
+}
+
+impl Default for TuningContext {
+    fn default() -> Self {
+        Self { a4_hz: 440.0 }
+    }
+}
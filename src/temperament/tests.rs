@@ -0,0 +1,63 @@
+use crate::{EqualTemperament, Pitch, PitchyError, Temperament};
+
+#[test]
+fn test_equal_temperament_matches_pitch() {
+    let temperament = EqualTemperament { a4_hz: 440.0 };
+
+    for midi in 0u8..=127 {
+        let expected = Pitch::try_from_midi_number(midi).unwrap();
+        let pitch = Pitch::from_midi_in(midi, &temperament).unwrap();
+
+        assert_eq!(pitch.frequency(), expected.frequency(), "MIDI {midi}");
+        let roundtrip = temperament.midi_from_frequency(pitch.frequency());
+        assert!((roundtrip - midi as f64).abs() < 1e-9, "MIDI {midi}");
+    }
+
+    assert_eq!(EqualTemperament::default(), temperament);
+}
+
+#[test]
+fn test_equal_temperament_reference() {
+    let baroque = EqualTemperament { a4_hz: 415.0 };
+
+    assert_eq!(baroque.frequency(69), 415.0);
+    assert_eq!(baroque.frequency(81), 830.0);
+    assert!((baroque.midi_from_frequency(415.0) - 69.0).abs() < 1e-9);
+    assert_eq!(
+        Pitch::from_midi_in(128, &baroque),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+}
+
+#[test]
+fn test_tuning_context() {
+    use crate::TuningContext;
+    use core::str::FromStr;
+
+    let context = TuningContext { a4_hz: 432.0 };
+    let a4 = context.pitch_from_str("A4").unwrap();
+    assert_eq!(a4.frequency(), 432.0);
+    assert_eq!(context.pitch_from_midi(69), Ok(a4));
+    assert_eq!(context.pitch_from_str("A5").unwrap().frequency(), 864.0);
+
+    for midi in [0, 60, 69, 127] {
+        let pitch = context.pitch_from_midi(midi).unwrap();
+        assert_eq!(context.midi_from_pitch(pitch), Ok(midi));
+    }
+    // 440 Hz is about 32 cents sharp of A4 at 432 Hz, so it still reads as A4
+    assert_eq!(context.midi_from_pitch(Pitch::new(440.0)), Ok(69));
+
+    assert_eq!(
+        context.pitch_from_midi(128),
+        Err(PitchyError::OutOfMidiRange(128))
+    );
+    assert_eq!(
+        context.pitch_from_str("C10"),
+        Err(PitchyError::OutOfMidiRange(132))
+    );
+    assert_eq!(context.pitch_from_str("H4"), Err(PitchyError::InvalidName));
+    assert!(context.midi_from_pitch(Pitch::new(2.0)).is_err());
+
+    let standard = TuningContext::default();
+    assert_eq!(standard.pitch_from_str("C#4"), Pitch::from_str("C#4"));
+}
@@ -73,3 +73,31 @@ fn test_pitch_note_from_str_roundtrip() {
 
     assert_eq!(midi, 127, "Expected to test all 128 (0-127) MIDI notes");
 }
+
+/// Ensures `Pitch::octave` and the octave of the spelled `Note` agree
+/// for every MIDI note, including the bottom of the range.
+#[test]
+fn test_pitch_note_octave_agreement() {
+    for midi in 0u8..=127 {
+        let pitch = Pitch::try_from_midi_number(midi).unwrap();
+        let note = Note::try_from(pitch).unwrap();
+
+        assert_eq!(
+            pitch.octave(),
+            Some(note.octave()),
+            "Octave mismatch at MIDI {midi}"
+        );
+    }
+}
+
+/// Checks that the `Pitch` and `Note` defaults describe the same A4 reference.
+#[test]
+fn test_defaults_are_a4() {
+    let pitch = Pitch::default();
+    let note = Note::default();
+
+    assert_eq!(pitch.frequency(), 440.0);
+    assert_eq!(note.octave(), 4);
+    assert_eq!(Pitch::try_from(note).unwrap(), pitch);
+    assert_eq!(Note::try_from(pitch).unwrap(), note);
+}